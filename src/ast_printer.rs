@@ -0,0 +1,226 @@
+use crate::{
+    expr::{Expr, ExprKind},
+    stmt::{FunctionDecl, Stmt},
+    value::Value,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AstFormat {
+    // (+ 1 (* 2 3))
+    Sexpr,
+    // +
+    //   1
+    //   *
+    //     2
+    //     3
+    Tree,
+}
+
+// The shape shared by both formats: a label and its child nodes.
+struct Node {
+    label: String,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn leaf(label: impl Into<String>) -> Node {
+        Node {
+            label: label.into(),
+            children: vec![],
+        }
+    }
+
+    fn new(label: impl Into<String>, children: Vec<Node>) -> Node {
+        Node {
+            label: label.into(),
+            children,
+        }
+    }
+
+    fn render(&self, format: AstFormat) -> String {
+        let mut out = String::new();
+        match format {
+            AstFormat::Sexpr => self.write_sexpr(&mut out),
+            AstFormat::Tree => self.write_tree(&mut out, 0),
+        }
+        out
+    }
+
+    fn write_sexpr(&self, out: &mut String) {
+        if self.children.is_empty() {
+            out.push_str(&self.label);
+            return;
+        }
+        out.push('(');
+        out.push_str(&self.label);
+        for child in self.children.iter() {
+            out.push(' ');
+            child.write_sexpr(out);
+        }
+        out.push(')');
+    }
+
+    fn write_tree(&self, out: &mut String, depth: usize) {
+        out.push_str(&"  ".repeat(depth));
+        out.push_str(&self.label);
+        out.push('\n');
+        for child in self.children.iter() {
+            child.write_tree(out, depth + 1);
+        }
+    }
+}
+
+impl Expr {
+    pub fn printer(&self, format: AstFormat) -> String {
+        expr_node(self).render(format)
+    }
+}
+
+impl Stmt {
+    pub fn printer(&self, format: AstFormat) -> String {
+        stmt_node(self).render(format)
+    }
+}
+
+fn literal_label(literal: &Value) -> String {
+    match literal {
+        Value::String(val) => format!("{:?}", val),
+        Value::None => "null".to_string(),
+        _ => format!("{}", literal),
+    }
+}
+
+fn expr_node(expr: &Expr) -> Node {
+    match &expr.kind {
+        ExprKind::Ternary(condition, then_value, else_value) => Node::new(
+            "?:",
+            vec![
+                expr_node(condition),
+                expr_node(then_value),
+                expr_node(else_value),
+            ],
+        ),
+        ExprKind::Binary(left, operator, right) | ExprKind::Logical(left, operator, right) => {
+            Node::new(
+                operator.lexeme.clone(),
+                vec![expr_node(left), expr_node(right)],
+            )
+        }
+        ExprKind::Grouping(inner) => Node::new("group", vec![expr_node(inner)]),
+        ExprKind::Literal(literal) => Node::leaf(literal_label(literal)),
+        ExprKind::Unary(operator, right) => {
+            Node::new(operator.lexeme.clone(), vec![expr_node(right)])
+        }
+        ExprKind::Variable(name) => Node::leaf(name.lexeme.clone()),
+        ExprKind::Assign(name, value) => {
+            Node::new("=", vec![Node::leaf(name.lexeme.clone()), expr_node(value)])
+        }
+        ExprKind::Call(callee, _, arguments) => {
+            let mut children = vec![expr_node(callee)];
+            children.extend(arguments.iter().map(expr_node));
+            Node::new("call", children)
+        }
+        ExprKind::Get(object, name) => Node::new(
+            ".",
+            vec![expr_node(object), Node::leaf(name.lexeme.clone())],
+        ),
+        ExprKind::Set(object, name, value) => Node::new(
+            ".=",
+            vec![
+                expr_node(object),
+                Node::leaf(name.lexeme.clone()),
+                expr_node(value),
+            ],
+        ),
+        ExprKind::This(_) => Node::leaf("this"),
+        ExprKind::Super(_, method) => Node::new(
+            ".",
+            vec![Node::leaf("super"), Node::leaf(method.lexeme.clone())],
+        ),
+        ExprKind::List(items) => Node::new("list", items.iter().map(expr_node).collect()),
+        ExprKind::Map(_, entries) => Node::new(
+            "map",
+            entries
+                .iter()
+                .map(|(key, value)| Node::new(":", vec![expr_node(key), expr_node(value)]))
+                .collect(),
+        ),
+        ExprKind::Interpolation(parts) => {
+            Node::new("interpolate", parts.iter().map(expr_node).collect())
+        }
+        ExprKind::Index(object, _, index) => {
+            Node::new("[]", vec![expr_node(object), expr_node(index)])
+        }
+        ExprKind::SetIndex(object, _, index, value) => Node::new(
+            "[]=",
+            vec![expr_node(object), expr_node(index), expr_node(value)],
+        ),
+        ExprKind::Slice(object, _, start, end) => {
+            let bound = |bound: &Option<Box<Expr>>| match bound {
+                Some(bound) => expr_node(bound),
+                None => Node::leaf("_"),
+            };
+            Node::new("[:]", vec![expr_node(object), bound(start), bound(end)])
+        }
+    }
+}
+
+fn function_node(label: &str, function: &FunctionDecl) -> Node {
+    let params: Vec<String> = function
+        .params
+        .iter()
+        .map(|param| param.lexeme.clone())
+        .collect();
+    Node::new(
+        format!("{} {}({})", label, function.name.lexeme, params.join(", ")),
+        function.body.iter().map(stmt_node).collect(),
+    )
+}
+
+fn stmt_node(stmt: &Stmt) -> Node {
+    match stmt {
+        Stmt::Expression(expr) => Node::new("expr", vec![expr_node(expr)]),
+        Stmt::Print(expr) => Node::new("print", vec![expr_node(expr)]),
+        Stmt::Var(name, initializer) => Node::new(
+            format!("var {}", name.lexeme),
+            initializer.iter().map(|expr| expr_node(expr)).collect(),
+        ),
+        Stmt::Block(statements) => Node::new("block", statements.iter().map(stmt_node).collect()),
+        Stmt::If(condition, then_branch, else_branch) => {
+            let mut children = vec![expr_node(condition), stmt_node(then_branch)];
+            if let Some(else_branch) = else_branch {
+                children.push(stmt_node(else_branch));
+            }
+            Node::new("if", children)
+        }
+        Stmt::While(condition, body) => {
+            Node::new("while", vec![expr_node(condition), stmt_node(body)])
+        }
+        Stmt::ForIn(name, iterable, body) => Node::new(
+            format!("for {} in", name.lexeme),
+            vec![expr_node(iterable), stmt_node(body)],
+        ),
+        Stmt::Delete(object, _, index) => {
+            Node::new("del", vec![expr_node(object), expr_node(index)])
+        }
+        Stmt::Function(function) => function_node("fun", function),
+        Stmt::Return(value) => {
+            Node::new("return", value.iter().map(|expr| expr_node(expr)).collect())
+        }
+        Stmt::Class(name, superclass, methods) => {
+            let mut label = format!("class {}", name.lexeme);
+            if let Some(superclass) = superclass {
+                if let ExprKind::Variable(superclass) = &superclass.kind {
+                    label += &format!(" < {}", superclass.lexeme);
+                }
+            }
+            Node::new(
+                label,
+                methods
+                    .iter()
+                    .map(|method| function_node("method", method))
+                    .collect(),
+            )
+        }
+    }
+}
@@ -0,0 +1,620 @@
+use std::{cmp::Ordering, fmt};
+
+// Results wider than this many bits are refused, so that a runaway `<<` or
+// `pow` fails instead of exhausting memory.
+pub const MAX_BITS: u64 = 1 << 24;
+
+// Whether a decimal number of `digits` digits, leading zeros not counted, may
+// be within `MAX_BITS`. Each digit adds log2(10) bits, so this refuses
+// numbers that are surely too large before they are parsed; those it lets
+// through are checked exactly once they are.
+pub fn digits_may_fit(digits: usize) -> bool {
+    let min_bits = (digits.saturating_sub(1) as f64 * std::f64::consts::LOG2_10).floor() as u64 + 1;
+    min_bits <= MAX_BITS
+}
+
+// An arbitrary-precision integer in sign-magnitude form. The magnitude is
+// stored as base 2^32 limbs, least significant first, without trailing zero
+// limbs; zero has an empty magnitude and is never negative, so derived
+// equality and hashing compare values.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    fn new(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        let negative = negative && !magnitude.is_empty();
+        BigInt {
+            negative,
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    // Number of bits in the magnitude.
+    pub fn bits(&self) -> u64 {
+        match self.magnitude.last() {
+            Some(top) => self.magnitude.len() as u64 * 32 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    // Parses an optionally signed run of decimal digits.
+    pub fn parse(text: &str) -> Option<BigInt> {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let mut magnitude = vec![];
+        for chunk in digits.as_bytes().chunks(9) {
+            let value: u32 = std::str::from_utf8(chunk).ok()?.parse().ok()?;
+            mul_small_add(&mut magnitude, 10u32.pow(chunk.len() as u32), value);
+        }
+        Some(BigInt::new(negative, magnitude))
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+        let mut value: u64 = 0;
+        for &limb in self.magnitude.iter().rev() {
+            value = value << 32 | limb as u64;
+        }
+        if self.negative {
+            if value <= i64::MAX as u64 + 1 {
+                return Some((value as i64).wrapping_neg());
+            }
+            return None;
+        }
+        i64::try_from(value).ok()
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let mut value = 0.0;
+        for &limb in self.magnitude.iter().rev() {
+            value = value * 4294967296.0 + limb as f64;
+        }
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    // The integer part of a finite float.
+    pub fn from_f64(value: f64) -> Option<BigInt> {
+        if !value.is_finite() {
+            return None;
+        }
+        let value = value.trunc();
+        if value.abs() < 9007199254740992.0 {
+            return Some(BigInt::from(value as i64));
+        }
+        // Beyond 2^53 every float is an integer: its mantissa times a power
+        // of two.
+        let bits = value.abs().to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) - 1075;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let magnitude = BigInt::from(mantissa as i64).shl(exponent).magnitude;
+        Some(BigInt::new(value < 0.0, magnitude))
+    }
+
+    pub fn neg(&self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::new(false, self.magnitude.clone())
+    }
+
+    pub fn add(&self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(
+                self.negative,
+                add_magnitudes(&self.magnitude, &other.magnitude),
+            );
+        }
+        match cmp_magnitudes(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::new(
+                other.negative,
+                sub_magnitudes(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::new(
+                self.negative,
+                sub_magnitudes(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+
+    pub fn sub(&self, other: &BigInt) -> BigInt {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            mul_magnitudes(&self.magnitude, &other.magnitude),
+        )
+    }
+
+    // Quotient rounded toward zero and the remainder with the sign of
+    // `self`, as for `i64`. `None` when dividing by zero.
+    pub fn div_rem(&self, other: &BigInt) -> Option<(BigInt, BigInt)> {
+        if other.is_zero() {
+            return None;
+        }
+        let (quotient, remainder) = div_rem_magnitudes(&self.magnitude, &other.magnitude);
+        Some((
+            BigInt::new(self.negative != other.negative, quotient),
+            BigInt::new(self.negative, remainder),
+        ))
+    }
+
+    // `self ^ exponent`, or `None` when the result would exceed `MAX_BITS`.
+    pub fn pow(&self, mut exponent: u64) -> Option<BigInt> {
+        if self.bits().saturating_sub(1).saturating_mul(exponent) > MAX_BITS {
+            return None;
+        }
+        let mut result = BigInt::from(1);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(&base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul(&base);
+            }
+        }
+        Some(result)
+    }
+
+    pub fn shl(&self, amount: u64) -> BigInt {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let (limbs, bits) = ((amount / 32) as usize, (amount % 32) as u32);
+        let mut magnitude = vec![0; limbs];
+        let mut carry = 0;
+        for &limb in self.magnitude.iter() {
+            magnitude.push(((limb as u64) << bits) as u32 | carry);
+            carry = if bits == 0 { 0 } else { limb >> (32 - bits) };
+        }
+        magnitude.push(carry);
+        BigInt::new(self.negative, magnitude)
+    }
+
+    // Rounds toward negative infinity, like `>>` on `i64`.
+    pub fn shr(&self, amount: u64) -> BigInt {
+        let (limbs, bits) = ((amount / 32) as usize, (amount % 32) as u32);
+        if limbs >= self.magnitude.len() {
+            return BigInt::from(if self.negative { -1 } else { 0 });
+        }
+        let lost = self.magnitude[..limbs].iter().any(|&limb| limb != 0)
+            || (bits > 0 && self.magnitude[limbs] << (32 - bits) != 0);
+        let mut magnitude = vec![];
+        for (i, &limb) in self.magnitude[limbs..].iter().enumerate() {
+            let next = self.magnitude.get(limbs + i + 1).copied().unwrap_or(0);
+            let high = if bits == 0 { 0 } else { next << (32 - bits) };
+            magnitude.push(limb >> bits | high);
+        }
+        let shifted = BigInt::new(self.negative, magnitude);
+        if self.negative && lost {
+            return shifted.sub(&BigInt::from(1));
+        }
+        shifted
+    }
+
+    pub fn bit_and(&self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |a, b| a & b)
+    }
+
+    pub fn bit_or(&self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |a, b| a | b)
+    }
+
+    pub fn bit_xor(&self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |a, b| a ^ b)
+    }
+
+    // Applies `op` limb by limb to the two's complement forms of both
+    // operands, as `i64` bit operations behave on negative numbers.
+    fn bitwise(&self, other: &BigInt, op: fn(u32, u32) -> u32) -> BigInt {
+        let len = self.magnitude.len().max(other.magnitude.len()) + 1;
+        let a = self.twos_complement(len);
+        let b = other.twos_complement(len);
+        let limbs: Vec<u32> = a.iter().zip(b.iter()).map(|(&a, &b)| op(a, b)).collect();
+        let negative = limbs[len - 1] >> 31 == 1;
+        if negative {
+            return BigInt::new(true, negate_limbs(limbs));
+        }
+        BigInt::new(false, limbs)
+    }
+
+    fn twos_complement(&self, len: usize) -> Vec<u32> {
+        let mut limbs = self.magnitude.clone();
+        limbs.resize(len, 0);
+        if self.negative {
+            return negate_limbs(limbs);
+        }
+        limbs
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        let magnitude = value.unsigned_abs();
+        BigInt::new(value < 0, vec![magnitude as u32, (magnitude >> 32) as u32])
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitudes(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_magnitudes(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        // Peel off nine decimal digits at a time, least significant first.
+        let mut chunks = vec![];
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            chunks.push(div_rem_small(&mut magnitude, 1_000_000_000));
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks.pop().unwrap())?;
+        for chunk in chunks.iter().rev() {
+            write!(f, "{:09}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+fn cmp_magnitudes(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in long.iter().enumerate() {
+        let total = limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        sum.push(total as u32);
+        carry = total >> 32;
+    }
+    sum.push(carry as u32);
+    sum
+}
+
+// `a - b` where `a >= b`.
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let mut total = limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        borrow = 0;
+        if total < 0 {
+            total += 1 << 32;
+            borrow = 1;
+        }
+        difference.push(total as u32);
+    }
+    difference
+}
+
+fn mul_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let total = x as u64 * y as u64 + product[i + j] as u64 + carry;
+            product[i + j] = total as u32;
+            carry = total >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    product
+}
+
+// `magnitude = magnitude * factor + addend`, in place.
+fn mul_small_add(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+    for limb in magnitude.iter_mut() {
+        let total = *limb as u64 * factor as u64 + carry;
+        *limb = total as u32;
+        carry = total >> 32;
+    }
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+// Divides `magnitude` by `divisor` in place and returns the remainder.
+fn div_rem_small(magnitude: &mut Vec<u32>, divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+    for limb in magnitude.iter_mut().rev() {
+        let current = remainder << 32 | *limb as u64;
+        *limb = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    while magnitude.last() == Some(&0) {
+        magnitude.pop();
+    }
+    remainder as u32
+}
+
+// Long division of magnitudes (Knuth, TAOCP vol. 2, algorithm D).
+fn div_rem_magnitudes(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_magnitudes(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    if b.len() == 1 {
+        let mut quotient = a.to_vec();
+        let remainder = div_rem_small(&mut quotient, b[0]);
+        return (quotient, vec![remainder]);
+    }
+    let n = b.len();
+    let m = a.len() - n;
+    // Normalize so the divisor's top limb has its high bit set, which keeps
+    // each estimated quotient digit at most two too large.
+    let shift = b[n - 1].leading_zeros();
+    let v = shift_limbs_left(b, shift, n);
+    let mut u = shift_limbs_left(a, shift, a.len() + 1);
+    let mut quotient = vec![0u32; m + 1];
+    let base = 1u64 << 32;
+    for j in (0..=m).rev() {
+        let numerator = (u[j + n] as u64) << 32 | u[j + n - 1] as u64;
+        let mut qhat = numerator / v[n - 1] as u64;
+        let mut rhat = numerator % v[n - 1] as u64;
+        while qhat >= base || qhat * v[n - 2] as u64 > (rhat << 32 | u[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += v[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+        // Subtract qhat * v from the current window of u.
+        let mut borrow = 0i64;
+        for i in 0..n {
+            let product = qhat * v[i] as u64;
+            let total = u[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+            u[i + j] = total as u32;
+            borrow = (product >> 32) as i64 - (total >> 32);
+        }
+        let total = u[j + n] as i64 - borrow;
+        u[j + n] = total as u32;
+        quotient[j] = qhat as u32;
+        // qhat was still one too large: add v back.
+        if total < 0 {
+            quotient[j] -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let total = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = total as u32;
+                carry = total >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+    }
+    let remainder = (0..n)
+        .map(|i| {
+            let high = if shift == 0 {
+                0
+            } else {
+                ((u[i + 1] as u64) << (32 - shift)) as u32
+            };
+            u[i] >> shift | high
+        })
+        .collect();
+    (quotient, remainder)
+}
+
+// `limbs << shift` for `shift < 32`, padded to `len` limbs.
+fn shift_limbs_left(limbs: &[u32], shift: u32, len: usize) -> Vec<u32> {
+    let mut shifted = vec![0u32; len];
+    for (i, &limb) in limbs.iter().enumerate() {
+        shifted[i] |= ((limb as u64) << shift) as u32;
+        if shift > 0 && i + 1 < len {
+            shifted[i + 1] = limb >> (32 - shift);
+        }
+    }
+    shifted
+}
+
+// Two's complement negation of a fixed-width limb vector.
+fn negate_limbs(mut limbs: Vec<u32>) -> Vec<u32> {
+    let mut carry = true;
+    for limb in limbs.iter_mut() {
+        let (sum, overflow) = (!*limb).overflowing_add(carry as u32);
+        *limb = sum;
+        carry = overflow;
+    }
+    limbs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(text: &str) -> BigInt {
+        BigInt::parse(text).unwrap()
+    }
+
+    fn div_rem(a: &str, b: &str) -> (String, String) {
+        let (quotient, remainder) = big(a).div_rem(&big(b)).unwrap();
+        (quotient.to_string(), remainder.to_string())
+    }
+
+    fn pair(quotient: &str, remainder: &str) -> (String, String) {
+        (quotient.to_string(), remainder.to_string())
+    }
+
+    #[test]
+    fn div_rem_with_normalized_divisor() {
+        // The divisor's top limb is 0xffffffff, so no shift is needed.
+        assert_eq!(
+            div_rem(
+                "170141183460469231731687303715884118073",
+                "18446744069414584327"
+            ),
+            pair("9223372039002259452", "18446744041497309269")
+        );
+    }
+
+    #[test]
+    fn div_rem_shifts_divisor_with_small_top_limb() {
+        // The divisor is 2^32 + 5; its top limb of 1 is shifted by 31 bits.
+        assert_eq!(
+            div_rem("1267650600228229401497690859697", "4294967301"),
+            pair("295147904835755442576", "987652321")
+        );
+    }
+
+    #[test]
+    fn div_rem_adds_back_when_quotient_digit_is_too_large() {
+        assert_eq!(
+            div_rem(
+                "189351592168042182188180165571978878394",
+                "79228162495817593519834398914"
+            ),
+            pair("2389953094", "79228162495817593519834338478")
+        );
+        assert_eq!(
+            div_rem(
+                "162424651634691206446259064036600277485",
+                "39614081257132168801066942463"
+            ),
+            pair("4100174646", "39614081257132168801066884387")
+        );
+    }
+
+    #[test]
+    fn div_rem_truncates_toward_zero() {
+        assert_eq!(
+            div_rem("-1267650600228229401496703205383", "1099511627779"),
+            pair("-1152921504603701248", "-9437191")
+        );
+        assert_eq!(
+            div_rem("1267650600228229401496703205383", "-1099511627779"),
+            pair("-1152921504603701248", "9437191")
+        );
+        assert!(big("1").div_rem(&BigInt::from(0)).is_none());
+    }
+
+    #[test]
+    fn bitwise_ops_on_negative_values() {
+        let x = big("-1180591620717411303429");
+        let y = big("36893488147419103241");
+        let z = big("-1099511627773");
+        assert_eq!(x.bit_and(&y).to_string(), "36893488147419103241");
+        assert_eq!(x.bit_or(&y).to_string(), "-1180591620717411303429");
+        assert_eq!(x.bit_xor(&y).to_string(), "-1217485108864830406670");
+        assert_eq!(x.bit_and(&z).to_string(), "-1180591621816922931197");
+        assert_eq!(x.bit_or(&z).to_string(), "-5");
+        assert_eq!(x.bit_xor(&z).to_string(), "1180591621816922931192");
+        assert_eq!(y.bit_and(&z).to_string(), "36893488147419103233");
+        assert_eq!(y.bit_or(&z).to_string(), "-1099511627765");
+        assert_eq!(y.bit_xor(&z).to_string(), "-36893489246930730998");
+    }
+
+    #[test]
+    fn shr_of_negative_values_rounds_down() {
+        let cases = [
+            ("-5", 1, "-3"),
+            ("-1", 1, "-1"),
+            ("-3", 0, "-3"),
+            ("-4294967296", 32, "-1"),
+            ("-18446744073709551615", 64, "-1"),
+            ("-18446744073709551616", 64, "-1"),
+            ("-18446744073709551617", 64, "-2"),
+            ("-39614081257132168796771975168", 33, "-4611686018427387904"),
+            (
+                "-1267650600228229401496703205377",
+                1,
+                "-633825300114114700748351602689",
+            ),
+            ("-1267650600228229401496703205376", 200, "-1"),
+        ];
+        for (value, amount, expected) in cases {
+            assert_eq!(
+                big(value).shr(amount).to_string(),
+                expected,
+                "{} >> {}",
+                value,
+                amount
+            );
+        }
+    }
+
+    #[test]
+    fn i64_min_edge_cases() {
+        let min = BigInt::from(i64::MIN);
+        assert_eq!(min.to_string(), "-9223372036854775808");
+        assert_eq!(big("-9223372036854775808"), min);
+        assert_eq!(min.to_i64(), Some(i64::MIN));
+        assert_eq!(min.neg().to_string(), "9223372036854775808");
+        assert_eq!(min.neg().to_i64(), None);
+        assert_eq!(min.abs(), min.neg());
+        assert_eq!(min.sub(&BigInt::from(1)).to_i64(), None);
+        assert_eq!(
+            min.mul(&min).to_string(),
+            "85070591730234615865843651857942052864"
+        );
+        let (quotient, remainder) = min.div_rem(&BigInt::from(-1)).unwrap();
+        assert_eq!(quotient.to_string(), "9223372036854775808");
+        assert!(remainder.is_zero());
+        assert_eq!(min.shr(63).to_i64(), Some(-1));
+        assert_eq!(min.shr(64).to_i64(), Some(-1));
+    }
+
+    #[test]
+    fn digit_bound() {
+        // 10^5050445 has 5050446 digits and needs exactly 16777216 bits;
+        // 10^5050446 needs 16777219.
+        assert!(digits_may_fit(0));
+        assert!(digits_may_fit(5050446));
+        assert!(!digits_may_fit(5050447));
+    }
+}
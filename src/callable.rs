@@ -0,0 +1,94 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use crate::{
+    error::Error,
+    interpreter::{Environment, Interpreter, Interrupt},
+    stmt::FunctionDecl,
+    token::Token,
+    value::Value,
+};
+
+pub trait Callable {
+    fn arity(&self) -> usize;
+    // `paren` is the closing parenthesis of the call, where errors raised by
+    // the callee itself are reported.
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<Value>,
+        paren: &Token,
+    ) -> Result<Value, Error>;
+}
+
+pub struct Function {
+    declaration: Rc<FunctionDecl>,
+    closure: Rc<RefCell<Environment>>,
+    is_initializer: bool,
+}
+
+impl Function {
+    pub fn new(
+        declaration: Rc<FunctionDecl>,
+        closure: Rc<RefCell<Environment>>,
+        is_initializer: bool,
+    ) -> Self {
+        Function {
+            declaration,
+            closure,
+            is_initializer,
+        }
+    }
+
+    pub fn bind(&self, instance: Value) -> Function {
+        let mut environment = Environment::new_enclosed(self.closure.clone());
+        environment.define("this".to_string(), instance);
+        Function::new(
+            self.declaration.clone(),
+            Rc::new(RefCell::new(environment)),
+            self.is_initializer,
+        )
+    }
+
+    pub fn name(&self) -> &str {
+        &self.declaration.name.lexeme
+    }
+
+    pub fn closure(&self) -> &Rc<RefCell<Environment>> {
+        &self.closure
+    }
+}
+
+impl Callable for Function {
+    fn arity(&self) -> usize {
+        self.declaration.params.len()
+    }
+
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<Value>,
+        _paren: &Token,
+    ) -> Result<Value, Error> {
+        let mut environment = Environment::new_enclosed(self.closure.clone());
+        for (param, argument) in self.declaration.params.iter().zip(arguments) {
+            environment.define(param.lexeme.clone(), argument);
+        }
+        let result =
+            interpreter.execute_block(&self.declaration.body, Rc::new(RefCell::new(environment)));
+        match result {
+            Err(Interrupt::Error(error)) => Err(error),
+            // An initializer always hands back the instance, even on a bare `return;`.
+            _ if self.is_initializer => Ok(self.closure.borrow().lookup("this").unwrap()),
+            Ok(()) => Ok(Value::None),
+            Err(Interrupt::Return(value)) => Ok(value),
+        }
+    }
+}
+
+// The closure environment can reach this function again, so only the name is
+// printed to keep `{:?}` from recursing forever.
+impl fmt::Debug for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<fn {}>", self.name())
+    }
+}
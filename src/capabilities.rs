@@ -0,0 +1,69 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+// What a script may do beyond computing and printing. Nothing is granted by
+// default, so untrusted scripts can be run as they are.
+#[derive(Debug, Clone, Default)]
+pub struct Capabilities {
+    // Directories (or single files) the file system built-ins may touch,
+    // along with everything below them.
+    fs_roots: Vec<PathBuf>,
+    // Whether scripts may read the host's stdin and write to its stderr.
+    stdio: bool,
+}
+
+impl Capabilities {
+    pub fn allow_stdio(&mut self) {
+        self.stdio = true;
+    }
+
+    pub fn check_stdio(&self) -> Result<(), String> {
+        if self.stdio {
+            return Ok(());
+        }
+        Err("Access to standard input and error is not allowed".to_string())
+    }
+
+    pub fn allow_fs(&mut self, path: &Path) -> io::Result<()> {
+        self.fs_roots.push(path.canonicalize()?);
+        Ok(())
+    }
+
+    // Resolves `path` and checks that it lies under a granted root. The file
+    // itself need not exist yet, only its parent directory. A symlink that
+    // can't be resolved is refused, since writing through it could create a
+    // file anywhere.
+    pub fn check_fs(&self, path: &str) -> Result<PathBuf, String> {
+        let denied = || format!("File system access to '{}' is not allowed", path);
+        if self.fs_roots.is_empty() {
+            return Err(denied());
+        }
+        let resolved = match Path::new(path).canonicalize() {
+            Ok(resolved) => resolved,
+            Err(_) => {
+                let path = Path::new(path);
+                let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+                    return Err(denied());
+                };
+                if path.symlink_metadata().is_ok_and(|meta| meta.is_symlink()) {
+                    return Err(denied());
+                }
+                let parent = if parent.as_os_str().is_empty() {
+                    Path::new(".")
+                } else {
+                    parent
+                };
+                match parent.canonicalize() {
+                    Ok(parent) => parent.join(name),
+                    Err(_) => return Err(denied()),
+                }
+            }
+        };
+        if self.fs_roots.iter().any(|root| resolved.starts_with(root)) {
+            return Ok(resolved);
+        }
+        Err(denied())
+    }
+}
@@ -0,0 +1,125 @@
+use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc};
+
+use crate::{
+    callable::{Callable, Function},
+    error::Error,
+    interpreter::Interpreter,
+    token::Token,
+    value::Value,
+};
+
+pub struct Class {
+    pub name: String,
+    superclass: Option<Rc<Class>>,
+    methods: HashMap<String, Rc<Function>>,
+}
+
+impl Class {
+    pub fn new(
+        name: String,
+        superclass: Option<Rc<Class>>,
+        methods: HashMap<String, Rc<Function>>,
+    ) -> Self {
+        Class {
+            name,
+            superclass,
+            methods,
+        }
+    }
+
+    pub fn find_method(&self, name: &str) -> Option<Rc<Function>> {
+        if let Some(method) = self.methods.get(name) {
+            return Some(method.clone());
+        }
+        if let Some(superclass) = &self.superclass {
+            return superclass.find_method(name);
+        }
+        None
+    }
+
+    // The class's own methods, without inherited ones.
+    pub fn methods(&self) -> impl Iterator<Item = &Rc<Function>> {
+        self.methods.values()
+    }
+
+    pub fn superclass(&self) -> Option<&Rc<Class>> {
+        self.superclass.as_ref()
+    }
+}
+
+impl Callable for Rc<Class> {
+    fn arity(&self) -> usize {
+        match self.find_method("init") {
+            Some(initializer) => initializer.arity(),
+            None => 0,
+        }
+    }
+
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<Value>,
+        paren: &Token,
+    ) -> Result<Value, Error> {
+        let instance = Value::Instance(Rc::new(RefCell::new(Instance::new(self.clone()))));
+        if let Some(initializer) = self.find_method("init") {
+            initializer
+                .bind(instance.clone())
+                .call(interpreter, arguments, paren)?;
+        }
+        Ok(instance)
+    }
+}
+
+impl fmt::Debug for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<class {}>", self.name)
+    }
+}
+
+pub struct Instance {
+    pub class: Rc<Class>,
+    fields: HashMap<String, Value>,
+}
+
+impl Instance {
+    pub fn new(class: Rc<Class>) -> Self {
+        Instance {
+            class,
+            fields: HashMap::new(),
+        }
+    }
+
+    // Fields shadow methods; methods come back bound to `instance` so `this`
+    // keeps working when they are stored and called later.
+    pub fn get(instance: &Rc<RefCell<Instance>>, name: &Token) -> Result<Value, Error> {
+        if let Some(value) = instance.borrow().fields.get(&name.lexeme) {
+            return Ok(value.clone());
+        }
+        let method = instance.borrow().class.find_method(&name.lexeme);
+        if let Some(method) = method {
+            let bound = method.bind(Value::Instance(instance.clone()));
+            return Ok(Value::Function(Rc::new(bound)));
+        }
+        Err(Error::new(
+            "R008",
+            name.span.clone(),
+            format!("Undefined property '{}'", name.lexeme),
+            format!("at '{}'", name.lexeme),
+        ))
+    }
+
+    pub fn set(&mut self, name: &Token, value: Value) {
+        self.fields.insert(name.lexeme.clone(), value);
+    }
+
+    pub fn take_fields(&mut self) -> Vec<Value> {
+        std::mem::take(&mut self.fields).into_values().collect()
+    }
+}
+
+impl fmt::Debug for Instance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{} instance>", self.class.name)
+    }
+}
@@ -0,0 +1,147 @@
+use hiscript::{AstFormat, ErrorFormat};
+
+// Exit statuses, following the BSD sysexits.h conventions.
+pub const EX_OK: u8 = 0;
+pub const EX_USAGE: u8 = 64;
+pub const EX_DATAERR: u8 = 65;
+pub const EX_NOINPUT: u8 = 66;
+pub const EX_SOFTWARE: u8 = 70;
+pub const EX_IOERR: u8 = 74;
+
+pub struct Options {
+    pub error_format: ErrorFormat,
+    // Stop after parsing, only reporting syntax errors.
+    pub check_only: bool,
+    pub dump_tokens: bool,
+    pub dump_ast: Option<AstFormat>,
+    // Made available to scripts as the `args` list.
+    pub script_args: Vec<String>,
+    // Paths the file system built-ins may access.
+    pub allow_fs: Vec<String>,
+    // Whether `read_line` and `eprint` may use stdin and stderr.
+    pub allow_stdio: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            error_format: ErrorFormat::Human,
+            check_only: false,
+            dump_tokens: false,
+            dump_ast: None,
+            script_args: vec![],
+            allow_fs: vec![],
+            allow_stdio: false,
+        }
+    }
+}
+
+pub const USAGE: &str = "Usage: hiscript [options] [script | -e <source> | -] [--] [args...]";
+
+const HELP: &str = "\
+Options:
+  -e <source>            Run <source> as the program
+  -                      Read the program from standard input
+  --check                Lex and parse the program without running it
+  --error-format=<fmt>   Report errors as 'human' (default) or 'json'
+  --dump-tokens          Print the tokens of the program instead of running it
+  --dump-ast[=<fmt>]     Print the syntax tree as 'sexpr' (default) or 'tree'
+                         instead of running the program
+  --allow-fs=<path>      Let the script read and write files under <path>;
+                         may be repeated
+  --allow-stdio          Let the script read stdin and write to stderr
+  --                     Pass the remaining arguments to the script as `args`
+  -h, --help             Show this help
+  -V, --version          Show the version
+
+Without a script, -e or -, an interactive prompt is started. Arguments
+after the script name are passed to it as `args` as well.";
+
+// Where the program to run comes from.
+pub enum Input {
+    File(String),
+    Eval(String),
+    Stdin,
+    Prompt,
+}
+
+pub enum Command {
+    Run(Input, Options),
+    Help,
+    Version,
+}
+
+pub fn help() -> String {
+    format!("{}\n\n{}", USAGE, HELP)
+}
+
+pub fn version() -> String {
+    format!("hiscript {}", env!("CARGO_PKG_VERSION"))
+}
+
+pub fn parse_args(args: Vec<String>) -> Result<Command, String> {
+    let mut options = Options::default();
+    let mut input = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "--check" => options.check_only = true,
+            "--dump-tokens" => options.dump_tokens = true,
+            "--dump-ast" => options.dump_ast = Some(AstFormat::Sexpr),
+            "--allow-stdio" => options.allow_stdio = true,
+            "--" => {
+                options.script_args.extend(args.by_ref());
+            }
+            "-e" => {
+                let Some(source) = args.next() else {
+                    return Err("'-e' expects the program source after it".to_string());
+                };
+                input = Some(Input::Eval(source));
+            }
+            "-" => input = Some(Input::Stdin),
+            _ => {
+                if let Some(format) = arg.strip_prefix("--error-format=") {
+                    options.error_format = match format {
+                        "human" => ErrorFormat::Human,
+                        "json" => ErrorFormat::Json,
+                        _ => {
+                            return Err(format!(
+                                "Unknown error format '{}', expected 'human' or 'json'",
+                                format
+                            ))
+                        }
+                    };
+                } else if let Some(path) = arg.strip_prefix("--allow-fs=") {
+                    if path.is_empty() {
+                        return Err("'--allow-fs=' expects a path".to_string());
+                    }
+                    options.allow_fs.push(path.to_string());
+                } else if let Some(format) = arg.strip_prefix("--dump-ast=") {
+                    options.dump_ast = Some(match format {
+                        "sexpr" => AstFormat::Sexpr,
+                        "tree" => AstFormat::Tree,
+                        _ => {
+                            return Err(format!(
+                                "Unknown AST format '{}', expected 'sexpr' or 'tree'",
+                                format
+                            ))
+                        }
+                    });
+                } else if arg.starts_with('-') {
+                    return Err(format!("Unknown option '{}'", arg));
+                } else if input.is_some() {
+                    return Err(format!(
+                        "Unexpected argument '{}', use '--' to pass arguments to the script",
+                        arg
+                    ));
+                } else {
+                    input = Some(Input::File(arg));
+                    options.script_args.extend(args.by_ref());
+                }
+            }
+        }
+    }
+    return Ok(Command::Run(input.unwrap_or(Input::Prompt), options));
+}
@@ -0,0 +1,163 @@
+use std::{fs, io, path::Path, rc::Rc};
+
+use crate::{
+    ast_printer::AstFormat,
+    error::Diagnostics,
+    hiscript::HiScript,
+    native::{HostFn, NativeFunction},
+    value::Value,
+};
+
+/// An embeddable HiScript interpreter.
+///
+/// Everything a call defines stays visible to later calls, so an `Engine`
+/// can be fed a program piece by piece.
+///
+/// Script calls may nest 1000 deep, and a run may use 4 MiB of stack beyond
+/// where the engine was called, before failing with a "Stack overflow"
+/// runtime error. Leave some stack to spare beyond that limit, or change it
+/// with `set_stack_limit`.
+pub struct Engine {
+    hiscript: HiScript,
+    evals: usize,
+}
+
+impl Engine {
+    pub fn new() -> Self {
+        Engine {
+            hiscript: HiScript::new(),
+            evals: 0,
+        }
+    }
+
+    /// Runs `source` and returns the value of its final expression, or
+    /// `Value::None` when it doesn't end in one. A lone expression doesn't
+    /// need a trailing `;`.
+    pub fn eval(&mut self, source: &str) -> Result<Value, Diagnostics> {
+        self.evals += 1;
+        let file = format!("<eval:{}>", self.evals);
+        self.hiscript.eval(source, &file)
+    }
+
+    /// Like `eval`, with `file` as the name diagnostics refer to `source` by.
+    pub fn eval_named(&mut self, source: &str, file: &str) -> Result<Value, Diagnostics> {
+        self.hiscript.eval(source, file)
+    }
+
+    /// Runs `source` as a program.
+    pub fn run(&mut self, source: &str, file: &str) -> Result<(), Diagnostics> {
+        self.hiscript.run(source, file)
+    }
+
+    /// Reads and runs the program at `path`. The outer error is a failure to
+    /// read the file, the inner one errors in the program itself.
+    pub fn run_file(&mut self, path: impl AsRef<Path>) -> io::Result<Result<(), Diagnostics>> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)?;
+        Ok(self.run(&source, &path.display().to_string()))
+    }
+
+    /// Lexes and parses `source`, reporting syntax errors without running it.
+    pub fn check(&mut self, source: &str, file: &str) -> Result<(), Diagnostics> {
+        self.hiscript.check(source, file)
+    }
+
+    /// The tokens of `source`, one per line.
+    pub fn dump_tokens(&mut self, source: &str, file: &str) -> Result<String, Diagnostics> {
+        self.hiscript.dump_tokens(source, file)
+    }
+
+    /// The syntax tree of the program `source`.
+    pub fn dump_ast(
+        &mut self,
+        source: &str,
+        file: &str,
+        format: AstFormat,
+    ) -> Result<String, Diagnostics> {
+        self.hiscript.dump_ast(source, file, format)
+    }
+
+    /// The syntax tree of `source` read as a single expression.
+    pub fn expression_ast(
+        &mut self,
+        source: &str,
+        file: &str,
+        format: AstFormat,
+    ) -> Result<String, Diagnostics> {
+        self.hiscript.expression_ast(source, file, format)
+    }
+
+    /// Whether `source` is cut off, with an unterminated string or unclosed
+    /// brackets, so that an interactive caller should ask for more input.
+    pub fn is_complete(source: &str) -> bool {
+        !HiScript::is_incomplete(source)
+    }
+
+    pub fn get_global(&self, name: &str) -> Option<Value> {
+        self.hiscript.globals().borrow().lookup(name)
+    }
+
+    pub fn set_global(&mut self, name: &str, value: Value) {
+        self.hiscript
+            .globals()
+            .borrow_mut()
+            .define(name.to_string(), value);
+    }
+
+    /// Defines a global function `name` that calls `function`.
+    ///
+    /// Arguments are converted with `FromValue` and the result with
+    /// `IntoValue`; a script passing the wrong number or type of arguments,
+    /// or a function returning `Err`, gets a runtime error at the call.
+    pub fn register_fn<Args, F: HostFn<Args> + 'static>(&mut self, name: &str, function: F) {
+        let function = NativeFunction::new(name, function);
+        self.set_global(name, Value::Native(Rc::new(function)));
+    }
+
+    /// Makes `args` available to scripts as a list of strings named `args`.
+    pub fn set_args(&mut self, args: &[String]) {
+        let args = args.iter().map(|arg| Value::String(arg.clone())).collect();
+        self.set_global("args", Value::new_list(args));
+    }
+
+    /// Lets the file system built-ins (`read_file`, `write_file`,
+    /// `append_file`, `exists` and `list_dir`) access `path` and everything
+    /// below it. Without any such grant they fail with a runtime error.
+    /// Fails if `path` doesn't exist.
+    pub fn allow_fs(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.hiscript
+            .capabilities()
+            .borrow_mut()
+            .allow_fs(path.as_ref())
+    }
+
+    /// Sets how many bytes of stack a run may use beyond where the engine was
+    /// called before it fails with a "Stack overflow" runtime error. The
+    /// thread should have a few megabytes more than this to spare.
+    pub fn set_stack_limit(&mut self, bytes: usize) {
+        self.hiscript.set_stack_limit(bytes);
+    }
+
+    /// Lets `read_line` read the host's standard input and `eprint` write to
+    /// its standard error. Without this grant they fail with a runtime error.
+    pub fn allow_stdio(&mut self) {
+        self.hiscript.capabilities().borrow_mut().allow_stdio();
+    }
+
+    /// Forgets all globals, including any set by `set_global` or `set_args`.
+    /// Granted capabilities are kept.
+    ///
+    /// So that script data can be freed even where it refers to itself,
+    /// lists, maps and instances reachable from the globals are emptied, and
+    /// functions lose the variables they captured. Values taken out of the
+    /// engine before the reset are affected too.
+    pub fn reset(&mut self) {
+        self.hiscript.reset();
+    }
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,32 +1,137 @@
-pub struct Error {
-    pub line: usize,
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{stderr, IsTerminal, Write},
+    ops::{Deref, DerefMut},
+    rc::{Rc, Weak},
+};
+
+use crate::span::Span;
+
+// Errors travel up through every `Result` in the interpreter, so the details
+// are boxed to keep the error path one pointer wide.
+pub struct Error(Box<ErrorDetails>);
+
+pub struct ErrorDetails {
+    pub code: &'static str,
+    pub span: Span,
     pub message: String,
     pub why: String,
+    pub labels: Vec<Label>,
+    pub help: Vec<String>,
+}
+
+// A secondary location attached to an error, e.g. the opening bracket that an
+// unclosed-bracket error refers back to.
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+}
+
+// The stage of a run an error came from. Codes are prefixed to match:
+// `L` for the lexer, `P` for the parser and `R` for the interpreter.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Phase {
+    Lex,
+    Parse,
+    Runtime,
+}
+
+impl Phase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Lex => "lex",
+            Phase::Parse => "parse",
+            Phase::Runtime => "runtime",
+        }
+    }
+}
+
+// Codes that are looked for after the fact, not only reported. The REPL
+// reads on after an unterminated string or interpolation, and a failed
+// `print` makes the CLI exit with EX_IOERR.
+pub const UNTERMINATED_STRING: &str = "L002";
+pub const UNTERMINATED_INTERPOLATION: &str = "L003";
+pub const PRINT_FAILED: &str = "R016";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ErrorFormat {
+    Human,
+    Json,
 }
 
+// An error from a value operation, before it is given a span. `code` is the
+// runtime error code it is reported under.
 pub struct ErrorMessage {
+    pub code: &'static str,
     pub message: String,
 }
 
 impl ErrorMessage {
-    pub fn new(message: &str) -> Self {
+    pub fn new(code: &'static str, message: &str) -> Self {
         ErrorMessage {
+            code,
             message: message.to_owned(),
         }
     }
-    pub fn get_message(self)->String{
+    #[allow(dead_code)]
+    pub fn get_message(self) -> String {
         return self.message;
     }
 }
 
 impl Error {
-    pub fn new(line: usize, message: String, why: String) -> Self {
-        Error { line, message, why }
+    pub fn new(code: &'static str, span: Span, message: String, why: String) -> Self {
+        Error(Box::new(ErrorDetails {
+            code,
+            span,
+            message,
+            why,
+            labels: vec![],
+            help: vec![],
+        }))
+    }
+
+    pub fn with_label(mut self, span: Span, message: &str) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.to_string(),
+        });
+        self
+    }
+
+    pub fn with_help(mut self, help: &str) -> Self {
+        self.help.push(help.to_string());
+        self
     }
 }
 
+impl Deref for Error {
+    type Target = ErrorDetails;
+    fn deref(&self) -> &ErrorDetails {
+        &self.0
+    }
+}
+
+impl DerefMut for Error {
+    fn deref_mut(&mut self) -> &mut ErrorDetails {
+        &mut self.0
+    }
+}
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+// `sources` holds each source by the address of the file name its spans
+// share, rather than by the name itself, so running different code under a
+// name already used doesn't change what earlier spans point into. Once no
+// span points into a source, say because the functions an earlier `eval`
+// defined are gone, it is dropped.
 pub struct ErrorManager {
-    errors: Vec<Error>,
+    errors: Vec<(Phase, Error)>,
+    sources: HashMap<usize, (Weak<str>, String)>,
     has_error: bool,
     has_runtime_error: bool,
 }
@@ -35,31 +140,304 @@ impl ErrorManager {
     pub fn new() -> Self {
         ErrorManager {
             errors: vec![],
+            sources: HashMap::new(),
             has_error: false,
             has_runtime_error: false,
         }
     }
-    pub fn add_error(&mut self, line: usize, message: String, why: String) {
+    // Registers the text of `file` so its errors can be shown with snippets,
+    // and returns the name for its spans to share; the text is kept as long
+    // as they are.
+    pub fn add_source(&mut self, file: &str, source: &str) -> Rc<str> {
+        self.sources.retain(|_, (name, _)| name.strong_count() > 0);
+        let name: Rc<str> = Rc::from(file);
+        self.sources.insert(
+            source_key(&name),
+            (Rc::downgrade(&name), source.to_string()),
+        );
+        name
+    }
+    pub fn add_lex_error(&mut self, error: Error) {
         self.has_error = true;
-        self.errors.push(Error::new(line, message, why));
+        self.errors.push((Phase::Lex, error));
+    }
+    pub fn add_parse_error(&mut self, error: Error) {
+        self.has_error = true;
+        self.errors.push((Phase::Parse, error));
     }
     pub fn add_runtime_error(&mut self, error: Error) {
         self.has_runtime_error = true;
-        self.errors.push(error);
+        self.errors.push((Phase::Runtime, error));
     }
-    pub fn report_errors(&self) {
-        for error in self.errors.iter() {
-            println!(
-                "[line {}] Error {}: {}",
-                error.line, error.why, error.message
-            );
+    // Hands over the errors collected so far, along with the sources they
+    // point into, and starts afresh.
+    pub fn take_diagnostics(&mut self) -> Diagnostics {
+        let errors = std::mem::take(&mut self.errors);
+        let mut sources = HashMap::new();
+        for (_, error) in errors.iter() {
+            let spans = std::iter::once(&error.span).chain(error.labels.iter().map(|l| &l.span));
+            for span in spans {
+                if let Some((_, source)) = self.sources.get(&source_key(&span.file)) {
+                    sources.insert(source_key(&span.file), source.clone());
+                }
+            }
         }
+        self.has_error = false;
+        self.has_runtime_error = false;
+        Diagnostics { errors, sources }
     }
     pub fn has_errors(&self) -> bool {
         return self.has_error;
     }
-    pub fn clear_errors(&mut self) {
-        self.has_error = false;
-        self.errors = vec![];
+    pub fn has_runtime_error(&self) -> bool {
+        return self.has_runtime_error;
+    }
+    pub fn has_error_code(&self, code: &str) -> bool {
+        self.errors.iter().any(|(_, error)| error.code == code)
+    }
+}
+
+// Identifies the source a span points into. The errors hold their spans, so
+// the address stays taken for as long as it is looked up.
+fn source_key(file: &Rc<str>) -> usize {
+    Rc::as_ptr(file) as *const u8 as usize
+}
+
+// The errors from one call into the engine, in the order they were found.
+#[derive(Default)]
+pub struct Diagnostics {
+    errors: Vec<(Phase, Error)>,
+    sources: HashMap<usize, String>,
+}
+
+impl Diagnostics {
+    pub fn iter(&self) -> impl Iterator<Item = (Phase, &Error)> {
+        self.errors.iter().map(|(phase, error)| (*phase, error))
+    }
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+    // Whether the program was rejected before it ran, as opposed to failing
+    // while running.
+    pub fn has_compile_errors(&self) -> bool {
+        self.errors
+            .iter()
+            .any(|(phase, _)| *phase != Phase::Runtime)
+    }
+
+    pub fn has_error_code(&self, code: &str) -> bool {
+        self.errors.iter().any(|(_, error)| error.code == code)
+    }
+
+    // Writes the diagnostics to stderr, colored if it is a terminal. In the
+    // JSON format a summary line follows, even when there are no errors.
+    // A closed stderr leaves nowhere to report to, so write errors are
+    // ignored.
+    pub fn report(&self, format: ErrorFormat) {
+        let text = match format {
+            ErrorFormat::Human => {
+                let color = stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+                self.render(color)
+            }
+            ErrorFormat::Json => self.to_json(),
+        };
+        let _ = stderr().lock().write_all(text.as_bytes());
+    }
+
+    pub fn render(&self, color: bool) -> String {
+        self.errors
+            .iter()
+            .map(|(_, error)| self.render_error(error, color))
+            .collect()
+    }
+
+    // One line of JSON per diagnostic, then the summary line.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        for (phase, error) in self.errors.iter() {
+            out += &Self::render_json(*phase, error);
+            out.push('\n');
+        }
+        let count = |phase| self.errors.iter().filter(|(p, _)| *p == phase).count();
+        out += &format!(
+            "{{\"type\":\"summary\",\"errors\":{},\"lex\":{},\"parse\":{},\"runtime\":{}}}\n",
+            self.errors.len(),
+            count(Phase::Lex),
+            count(Phase::Parse),
+            count(Phase::Runtime)
+        );
+        out
+    }
+
+    // One diagnostic as a single line of JSON, e.g.
+    //
+    //   {"type":"diagnostic","severity":"error","phase":"parse","code":"P001",
+    //    "message":"...","file":"a.hi","span":{...},"labels":[],"help":[]}
+    fn render_json(phase: Phase, error: &Error) -> String {
+        let labels: Vec<String> = error
+            .labels
+            .iter()
+            .map(|label| {
+                format!(
+                    "{{\"message\":{},\"span\":{}}}",
+                    json_string(&label.message),
+                    json_span(&label.span)
+                )
+            })
+            .collect();
+        let help: Vec<String> = error.help.iter().map(|help| json_string(help)).collect();
+        format!(
+            "{{\"type\":\"diagnostic\",\"severity\":\"error\",\"phase\":\"{}\",\"code\":\"{}\",\"message\":{},\"file\":{},\"span\":{},\"labels\":[{}],\"help\":[{}]}}",
+            phase.name(),
+            error.code,
+            json_string(&error.message),
+            json_string(&error.span.file),
+            json_span(&error.span),
+            labels.join(","),
+            help.join(",")
+        )
+    }
+
+    // Renders `error` in the style of rustc:
+    //
+    //   error[R001]: Undefined variable 'x'
+    //    --> script.hi:3:7
+    //     |
+    //   3 | print x;
+    //     |       ^
+    //     = help: ...
+    //
+    // falling back to a single `[line N]` line when the source is unknown.
+    fn render_error(&self, error: &Error, color: bool) -> String {
+        let paint = |style: &str, text: &str| {
+            if color {
+                format!("{}{}{}", style, text, RESET)
+            } else {
+                text.to_string()
+            }
+        };
+        let Some(source) = self.sources.get(&source_key(&error.span.file)) else {
+            let mut out = format!(
+                "[line {}] Error[{}] {}: {}\n",
+                error.span.line, error.code, error.why, error.message
+            );
+            for help in error.help.iter() {
+                out += &format!("  help: {}\n", help);
+            }
+            return out;
+        };
+
+        let mut marks: Vec<(&Span, &str, bool)> = vec![(&error.span, "", true)];
+        for label in error.labels.iter() {
+            marks.push((&label.span, &label.message, false));
+        }
+        marks.sort_by_key(|(span, _, _)| (span.line, span.column));
+        let gutter_width = marks
+            .iter()
+            .map(|(span, _, _)| span.line.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let gutter = " ".repeat(gutter_width);
+        let bar = paint(BLUE, "|");
+
+        let mut out = format!(
+            "{}{}\n",
+            paint(RED, &format!("error[{}]", error.code)),
+            paint(BOLD, &format!(": {}", error.message))
+        );
+        out += &format!(
+            "{}{} {}:{}:{}\n",
+            gutter,
+            paint(BLUE, "-->"),
+            error.span.file,
+            error.span.line,
+            error.span.column
+        );
+        out += &format!("{} {}\n", gutter, bar);
+        let mut last_line = 0;
+        for (span, message, primary) in marks {
+            let text = source.lines().nth(span.line - 1).unwrap_or("");
+            if span.line != last_line {
+                let number = format!("{:>width$}", span.line, width = gutter_width);
+                out += &format!(
+                    "{} {} {}\n",
+                    paint(BLUE, &number),
+                    bar,
+                    text.replace('\t', " ")
+                );
+                last_line = span.line;
+            }
+            // Underline from the span's column to its end, clipped to this line.
+            let available = text.chars().count().saturating_sub(span.column - 1);
+            let length = source
+                .get(span.start..span.end)
+                .map(|spanned| spanned.chars().take_while(|c| *c != '\n').count())
+                .unwrap_or(1)
+                .min(available)
+                .max(1);
+            let (marker, style) = if primary { ("^", RED) } else { ("-", BLUE) };
+            let mut underline = marker.repeat(length);
+            if !message.is_empty() {
+                underline = format!("{} {}", underline, message);
+            }
+            out += &format!(
+                "{} {} {}{}\n",
+                gutter,
+                bar,
+                " ".repeat(span.column - 1),
+                paint(style, &underline)
+            );
+        }
+        for help in error.help.iter() {
+            out += &format!(
+                "{} {} {}\n",
+                gutter,
+                paint(BLUE, "="),
+                paint(BOLD, "help:") + " " + help
+            );
+        }
+        out += "\n";
+        out
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(false).trim_end())
+    }
+}
+
+impl fmt::Debug for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Diagnostics {}
+
+fn json_span(span: &Span) -> String {
+    format!(
+        "{{\"start\":{},\"end\":{},\"line\":{},\"column\":{}}}",
+        span.start, span.end, span.line, span.column
+    )
+}
+
+fn json_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            '\r' => out += "\\r",
+            '\t' => out += "\\t",
+            c if (c as u32) < 0x20 => out += &format!("\\u{:04x}", c as u32),
+            c => out.push(c),
+        }
     }
+    out.push('"');
+    out
 }
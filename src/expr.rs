@@ -1,15 +1,35 @@
-use crate::{literal::Literal, token::Token};
+use crate::{span::Span, token::Token, value::Value};
 #[derive(Debug)]
-pub enum Expr {
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub enum ExprKind {
     Ternary(Box<Expr>, Box<Expr>, Box<Expr>),
     Binary(Box<Expr>, Token, Box<Expr>),
+    Logical(Box<Expr>, Token, Box<Expr>),
     Grouping(Box<Expr>),
-    Literal(Literal),
+    Literal(Value),
     Unary(Token, Box<Expr>),
+    Variable(Token),
+    Assign(Token, Box<Expr>),
+    Call(Box<Expr>, Token, Vec<Expr>),
+    Get(Box<Expr>, Token),
+    Set(Box<Expr>, Token, Box<Expr>),
+    This(Token),
+    Super(Token, Token),
+    List(Vec<Expr>),
+    Map(Token, Vec<(Expr, Expr)>),
+    Interpolation(Vec<Expr>),
+    Index(Box<Expr>, Token, Box<Expr>),
+    SetIndex(Box<Expr>, Token, Box<Expr>, Box<Expr>),
+    Slice(Box<Expr>, Token, Option<Box<Expr>>, Option<Box<Expr>>),
 }
 
 impl Expr {
-    fn printer(self) {
-        
+    pub fn boxed(kind: ExprKind, span: Span) -> Box<Expr> {
+        Box::new(Expr { kind, span })
     }
 }
@@ -1,54 +1,202 @@
-use std::{
-    error::Error,
-    fs,
-    io::{stdin, stdout, Write},
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    ast_printer::AstFormat,
+    capabilities::Capabilities,
+    error::{Diagnostics, ErrorManager, UNTERMINATED_INTERPOLATION, UNTERMINATED_STRING},
+    expr::Expr,
+    interpreter::{Environment, Interpreter, DEFAULT_STACK_LIMIT},
+    lexer::Lexer,
+    parser::Parser,
+    stdlib,
+    stmt::Stmt,
+    token::Token,
+    token_type::TokenType,
+    value::Value,
 };
 
-use crate::{error::ErrorManager, interpreter::Interpreter, lexer::Lexer, parser::Parser};
-pub struct HiScript {}
+// The lexer -> parser -> interpreter pipeline, together with the state that
+// outlives a single run: the global scope, the sources that errors in
+// functions defined by earlier runs point into, and what scripts may access.
+pub struct HiScript {
+    error_manager: ErrorManager,
+    globals: Rc<RefCell<Environment>>,
+    capabilities: Rc<RefCell<Capabilities>>,
+    stack_limit: usize,
+}
 
 impl HiScript {
     pub fn new() -> Self {
-        HiScript {}
-    }
-
-    pub fn run(&self, source: String) {
-        let mut error_manager = ErrorManager::new();
-        let mut lexer = Lexer::new(&source, &mut error_manager);
-        let tokens = lexer.scan_tokens();
-        let mut parser = Parser::new(tokens, &mut error_manager);
-        let result = parser.parse();
-        let mut interpreter = Interpreter::new(&mut error_manager);
-        match result {
-            Some(expr)=>{
-                let literal = interpreter.interpret(expr);
-                if let Some(val) = literal{
-                    val.print();
+        Self::with_capabilities(Rc::new(RefCell::new(Capabilities::default())))
+    }
+
+    fn with_capabilities(capabilities: Rc<RefCell<Capabilities>>) -> Self {
+        let mut globals = Environment::new();
+        stdlib::define(&mut globals, &capabilities);
+        HiScript {
+            error_manager: ErrorManager::new(),
+            globals: Rc::new(RefCell::new(globals)),
+            capabilities,
+            stack_limit: DEFAULT_STACK_LIMIT,
+        }
+    }
+
+    // Forgets the globals but keeps the capabilities and the stack limit,
+    // which belong to the host rather than to any script. The old scopes are
+    // emptied first so that functions and the scopes they close over can be
+    // freed.
+    pub fn reset(&mut self) {
+        Environment::clear_reachable(&self.globals);
+        let stack_limit = self.stack_limit;
+        *self = HiScript::with_capabilities(self.capabilities.clone());
+        self.stack_limit = stack_limit;
+    }
+
+    pub fn set_stack_limit(&mut self, bytes: usize) {
+        self.stack_limit = bytes;
+    }
+
+    pub fn capabilities(&self) -> &Rc<RefCell<Capabilities>> {
+        &self.capabilities
+    }
+
+    pub fn globals(&self) -> &Rc<RefCell<Environment>> {
+        &self.globals
+    }
+
+    // Runs `source` as a program.
+    pub fn run(&mut self, source: &str, file: &str) -> Result<(), Diagnostics> {
+        let tokens = self.scan(source, file);
+        let statements = Parser::new(tokens, &mut self.error_manager).parse();
+        if !self.error_manager.has_errors() {
+            self.interpreter().interpret(&statements);
+        }
+        self.finish(())
+    }
+
+    // Like `run`, but also gives back the value of a trailing expression
+    // statement, or of the whole of `source` when it is a single expression
+    // without a ';'.
+    pub fn eval(&mut self, source: &str, file: &str) -> Result<Value, Diagnostics> {
+        let tokens = self.scan(source, file);
+        if !self.error_manager.has_errors() {
+            if let Some(expr) = Self::lone_expression(tokens.clone()) {
+                let value = self.interpreter().interpret_expression(&expr);
+                return self.finish(value.unwrap_or(Value::None));
+            }
+        }
+        let mut statements = Parser::new(tokens, &mut self.error_manager).parse();
+        let mut value = Value::None;
+        if !self.error_manager.has_errors() {
+            let last = match statements.last() {
+                Some(Stmt::Expression(_)) => statements.pop(),
+                _ => None,
+            };
+            self.interpreter().interpret(&statements);
+            if let Some(Stmt::Expression(expr)) = last {
+                if !self.error_manager.has_runtime_error() {
+                    let result = self.interpreter().interpret_expression(&expr);
+                    value = result.unwrap_or(Value::None);
                 }
             }
-            None=>{
+        }
+        self.finish(value)
+    }
+
+    // Lexes and parses `source` without running it.
+    pub fn check(&mut self, source: &str, file: &str) -> Result<(), Diagnostics> {
+        let tokens = self.scan(source, file);
+        Parser::new(tokens, &mut self.error_manager).parse();
+        self.finish(())
+    }
+
+    pub fn dump_tokens(&mut self, source: &str, file: &str) -> Result<String, Diagnostics> {
+        let mut lexer = Lexer::new(source, file, &mut self.error_manager);
+        lexer.scan_tokens();
+        let dump = lexer.format_tokens();
+        self.finish(dump)
+    }
 
+    pub fn dump_ast(
+        &mut self,
+        source: &str,
+        file: &str,
+        format: AstFormat,
+    ) -> Result<String, Diagnostics> {
+        let tokens = self.scan(source, file);
+        let statements = Parser::new(tokens, &mut self.error_manager).parse();
+        let mut dump = String::new();
+        for statement in statements.iter() {
+            dump += &statement.printer(format);
+            if format == AstFormat::Sexpr {
+                dump.push('\n');
             }
         }
-        error_manager.report_errors();
+        self.finish(dump)
     }
-    pub fn run_file(self, path: &String) -> Result<(), Box<dyn Error>> {
-        let file_data = fs::read_to_string(path)?;
-        self.run(file_data);
-        return Ok(());
+
+    // The syntax tree of `source` read as a single expression.
+    pub fn expression_ast(
+        &mut self,
+        source: &str,
+        file: &str,
+        format: AstFormat,
+    ) -> Result<String, Diagnostics> {
+        let tokens = self.scan(source, file);
+        let expr = Parser::new(tokens, &mut self.error_manager).parse_expression();
+        let dump = expr.map(|expr| expr.printer(format)).unwrap_or_default();
+        self.finish(dump)
     }
 
-    pub fn run_prompt(&self) -> Result<(), Box<dyn Error>> {
-        loop {
-            print!("> ");
-            let _ = stdout().flush();
-            let mut line = String::new();
-            let bytes_read = stdin().read_line(&mut line)?;
-            if bytes_read == 0 {
-                break;
+    // True while `source` has an unterminated string or more opening than
+    // closing brackets, i.e. more input is needed before it can be run.
+    pub fn is_incomplete(source: &str) -> bool {
+        let mut scratch = ErrorManager::new();
+        let tokens = Lexer::new(source, "<input>", &mut scratch).scan_tokens();
+        if scratch.has_error_code(UNTERMINATED_STRING)
+            || scratch.has_error_code(UNTERMINATED_INTERPOLATION)
+        {
+            return true;
+        }
+        let mut depth = 0;
+        for token in tokens.iter() {
+            match token.token_type {
+                TokenType::LEFTPAREN | TokenType::LEFTBRACE | TokenType::LEFTBRACKET => depth += 1,
+                TokenType::RIGHTPAREN | TokenType::RIGHTBRACE | TokenType::RIGHTBRACKET => {
+                    depth -= 1
+                }
+                _ => (),
             }
-            self.run(line);
         }
-        return Ok(());
+        depth > 0
+    }
+
+    fn scan(&mut self, source: &str, file: &str) -> Vec<Token> {
+        Lexer::new(source, file, &mut self.error_manager).scan_tokens()
+    }
+
+    // The tokens as a single expression, if that is all they are.
+    fn lone_expression(tokens: Vec<Token>) -> Option<Box<Expr>> {
+        let mut scratch = ErrorManager::new();
+        let expr = Parser::new(tokens, &mut scratch).parse_expression();
+        if scratch.has_errors() {
+            return None;
+        }
+        expr
+    }
+
+    fn interpreter(&mut self) -> Interpreter<'_> {
+        Interpreter::new(
+            &mut self.error_manager,
+            self.globals.clone(),
+            self.stack_limit,
+        )
+    }
+
+    fn finish<T>(&mut self, value: T) -> Result<T, Diagnostics> {
+        if self.error_manager.has_errors() || self.error_manager.has_runtime_error() {
+            return Err(self.error_manager.take_diagnostics());
+        }
+        return Ok(value);
     }
 }
@@ -1,63 +1,398 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
 use crate::{
-    error::{Error, ErrorManager, ErrorMessage},
-    expr::Expr,
-    literal::{
-        Add, BitAnd, BitOr, Div, EqualTo, Greater, GreaterOrEqual, LeftShift, Lesser,
-        LesserOrEqual, Literal, Mod, Mul, NotEqual, RightShift, Sub, Xor,
-    },
+    bigint::BigInt,
+    callable::{Callable, Function},
+    class::{Class, Instance},
+    error::{Error, ErrorManager, ErrorMessage, PRINT_FAILED},
+    expr::{Expr, ExprKind},
+    map::{Map, MapKey},
+    span::Span,
+    stmt::Stmt,
     token::Token,
     token_type::TokenType,
+    value::{
+        Add, BitAnd, BitOr, Div, Greater, GreaterOrEqual, LeftShift, Lesser, LesserOrEqual, Mod,
+        Mul, RightShift, Sub, Value, Xor,
+    },
 };
 
+pub struct Environment {
+    values: HashMap<String, Value>,
+    enclosing: Option<Rc<RefCell<Environment>>>,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Environment {
+            values: HashMap::new(),
+            enclosing: None,
+        }
+    }
+
+    pub fn new_enclosed(enclosing: Rc<RefCell<Environment>>) -> Self {
+        Environment {
+            values: HashMap::new(),
+            enclosing: Some(enclosing),
+        }
+    }
+
+    pub fn define(&mut self, name: String, value: Value) {
+        self.values.insert(name, value);
+    }
+
+    pub fn get(&self, name: &Token) -> Result<Value, Error> {
+        self.lookup(&name.lexeme)
+            .ok_or_else(|| Self::undefined(name))
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<Value> {
+        if let Some(value) = self.values.get(name) {
+            return Some(value.clone());
+        }
+        if let Some(enclosing) = &self.enclosing {
+            return enclosing.borrow().lookup(name);
+        }
+        None
+    }
+
+    pub fn assign(&mut self, name: &Token, value: Value) -> Result<(), Error> {
+        if let Some(slot) = self.values.get_mut(&name.lexeme) {
+            *slot = value;
+            return Ok(());
+        }
+        if let Some(enclosing) = &self.enclosing {
+            return enclosing.borrow_mut().assign(name, value);
+        }
+        Err(Self::undefined(name))
+    }
+
+    // Empties `root` and every environment, instance, list and map reachable
+    // from it. Functions keep the scope they were defined in alive and that
+    // scope keeps them, as can containers holding themselves, so without
+    // this a dropped global scope would never be freed.
+    pub fn clear_reachable(root: &Rc<RefCell<Environment>>) {
+        let mut environments = vec![root.clone()];
+        let mut values = vec![];
+        while !environments.is_empty() || !values.is_empty() {
+            if let Some(environment) = environments.pop() {
+                let mut environment = environment.borrow_mut();
+                values.extend(std::mem::take(&mut environment.values).into_values());
+                environments.extend(environment.enclosing.take());
+                continue;
+            }
+            match values.pop().unwrap() {
+                Value::Function(function) => environments.push(function.closure().clone()),
+                Value::Class(class) => {
+                    values.extend(class.methods().cloned().map(Value::Function));
+                    values.extend(class.superclass().cloned().map(Value::Class));
+                }
+                Value::Instance(instance) => {
+                    values.extend(instance.borrow_mut().take_fields());
+                }
+                Value::List(items) => values.extend(std::mem::take(&mut *items.borrow_mut())),
+                Value::Map(map) => {
+                    let map = std::mem::take(&mut *map.borrow_mut());
+                    values.extend(map.entries().iter().map(|(_, value)| value.clone()));
+                }
+                _ => (),
+            }
+        }
+    }
+
+    fn undefined(name: &Token) -> Error {
+        Error::new(
+            "R001",
+            name.span.clone(),
+            format!("Undefined variable '{}'", name.lexeme),
+            format!("at '{}'", name.lexeme),
+        )
+    }
+}
+
+// Anything that stops a run of statements early: a runtime error, or a
+// `return` unwinding to the enclosing function call.
+pub enum Interrupt {
+    Error(Error),
+    Return(Value),
+}
+
+impl From<Error> for Interrupt {
+    fn from(error: Error) -> Self {
+        Interrupt::Error(error)
+    }
+}
+
+// Calls nested deeper than this fail with a runtime error instead of
+// overflowing the Rust stack.
+const MAX_CALL_DEPTH: usize = 1000;
+
+// How many bytes of stack a run may use before evaluating another expression
+// fails with a runtime error, unless the host sets another limit.
+pub const DEFAULT_STACK_LIMIT: usize = 4 * 1024 * 1024;
+
 pub struct Interpreter<'a> {
     error_manager: &'a mut ErrorManager,
+    environment: Rc<RefCell<Environment>>,
+    call_depth: usize,
+    // Where the stack stood when the run began, and how far past that it may
+    // grow. How much stack a call takes depends on how deeply the expressions
+    // in it nest, so `MAX_CALL_DEPTH` alone can't keep it in bounds.
+    stack_start: usize,
+    stack_limit: usize,
 }
 
 impl<'a> Interpreter<'a> {
-    pub fn new(error_manager: &'a mut ErrorManager) -> Self {
-        Interpreter { error_manager }
+    pub fn new(
+        error_manager: &'a mut ErrorManager,
+        globals: Rc<RefCell<Environment>>,
+        stack_limit: usize,
+    ) -> Self {
+        Interpreter {
+            error_manager,
+            environment: globals,
+            call_depth: 0,
+            stack_start: stack_position(),
+            stack_limit,
+        }
+    }
+
+    pub fn interpret(&mut self, statements: &[Stmt]) {
+        for statement in statements {
+            match self.execute(statement) {
+                Ok(()) => (),
+                Err(Interrupt::Error(error)) => {
+                    self.error_manager.add_runtime_error(error);
+                    return;
+                }
+                Err(Interrupt::Return(_)) => return,
+            }
+        }
     }
 
-    pub fn interpret(&mut self, expr: Box<Expr>) -> Option<Literal> {
-        let res = self.evaluate(expr);
-        match res {
-            Ok(val) => Some(val),
-            Err(val) => {
-                self.error_manager.add_runtime_error(val);
+    pub fn interpret_expression(&mut self, expr: &Expr) -> Option<Value> {
+        match self.evaluate(expr) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.error_manager.add_runtime_error(error);
                 None
             }
         }
     }
 
-    fn evaluate(&mut self, expr: Box<Expr>) -> Result<Literal, Error> {
-        match *expr {
-            Expr::Literal(literal) => {
-                return Ok(literal);
+    fn execute(&mut self, stmt: &Stmt) -> Result<(), Interrupt> {
+        match stmt {
+            Stmt::Expression(expr) => {
+                self.evaluate(expr)?;
+            }
+            Stmt::Print(expr) => {
+                let value = self.evaluate(expr)?;
+                if let Err(err) = value.print() {
+                    return Err(Error::new(
+                        PRINT_FAILED,
+                        expr.span.clone(),
+                        format!("Could not write to standard output: {}", err),
+                        "".to_string(),
+                    )
+                    .into());
+                }
+            }
+            Stmt::Var(name, initializer) => {
+                let value = match initializer {
+                    Some(expr) => self.evaluate(expr)?,
+                    None => Value::None,
+                };
+                self.environment
+                    .borrow_mut()
+                    .define(name.lexeme.clone(), value);
+            }
+            Stmt::Block(statements) => {
+                let environment = Environment::new_enclosed(self.environment.clone());
+                self.execute_block(statements, Rc::new(RefCell::new(environment)))?;
+            }
+            Stmt::If(condition, then_branch, else_branch) => {
+                if Self::is_true(&self.evaluate(condition)?) {
+                    self.execute(then_branch)?;
+                } else if let Some(else_branch) = else_branch {
+                    self.execute(else_branch)?;
+                }
+            }
+            Stmt::While(condition, body) => {
+                while Self::is_true(&self.evaluate(condition)?) {
+                    self.execute(body)?;
+                }
+            }
+            Stmt::ForIn(name, iterable, body) => {
+                let iterable = self.evaluate(iterable)?;
+                // Iterate over a snapshot so the body may mutate the container.
+                let values = match &iterable {
+                    Value::List(items) => items.borrow().clone(),
+                    Value::Map(map) => map.borrow().keys().iter().map(MapKey::to_value).collect(),
+                    Value::String(val) => {
+                        val.chars().map(|c| Value::String(c.to_string())).collect()
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            "R003",
+                            name.span.clone(),
+                            format!("{} is not iterable", iterable.type_name()),
+                            "".to_string(),
+                        )
+                        .into())
+                    }
+                };
+                for value in values {
+                    let mut environment = Environment::new_enclosed(self.environment.clone());
+                    environment.define(name.lexeme.clone(), value);
+                    self.execute_block(
+                        std::slice::from_ref(body.as_ref()),
+                        Rc::new(RefCell::new(environment)),
+                    )?;
+                }
+            }
+            Stmt::Delete(object, bracket, index) => {
+                let object = self.evaluate(object)?;
+                let index = self.evaluate(index)?;
+                match &object {
+                    Value::List(items) => {
+                        let mut items = items.borrow_mut();
+                        let position = Self::list_position(items.len(), &index, bracket)?;
+                        items.remove(position);
+                    }
+                    Value::Map(map) => {
+                        let key = Self::map_operator_result(MapKey::from_value(&index), bracket)?;
+                        if map.borrow_mut().remove(&key).is_none() {
+                            return Err(Self::missing_key(&index, bracket).into());
+                        }
+                    }
+                    _ => return Err(Self::not_indexable(&object, bracket).into()),
+                }
+            }
+            Stmt::Function(declaration) => {
+                let function = Function::new(declaration.clone(), self.environment.clone(), false);
+                self.environment.borrow_mut().define(
+                    declaration.name.lexeme.clone(),
+                    Value::Function(Rc::new(function)),
+                );
+            }
+            Stmt::Class(name, superclass, methods) => {
+                let superclass = match superclass {
+                    Some(expr) => match self.evaluate(expr)? {
+                        Value::Class(class) => Some(class),
+                        _ => {
+                            return Err(Error::new(
+                                "R004",
+                                name.span.clone(),
+                                "Superclass must be a class".to_string(),
+                                format!("at '{}'", name.lexeme),
+                            )
+                            .into())
+                        }
+                    },
+                    None => None,
+                };
+                self.environment
+                    .borrow_mut()
+                    .define(name.lexeme.clone(), Value::None);
+
+                let mut closure = self.environment.clone();
+                if let Some(superclass) = &superclass {
+                    let mut environment = Environment::new_enclosed(closure);
+                    environment.define("super".to_string(), Value::Class(superclass.clone()));
+                    closure = Rc::new(RefCell::new(environment));
+                }
+                let mut class_methods = HashMap::new();
+                for method in methods {
+                    let is_initializer = method.name.lexeme == "init";
+                    let function = Function::new(method.clone(), closure.clone(), is_initializer);
+                    class_methods.insert(method.name.lexeme.clone(), Rc::new(function));
+                }
+                let class = Class::new(name.lexeme.clone(), superclass, class_methods);
+                self.environment
+                    .borrow_mut()
+                    .assign(name, Value::Class(Rc::new(class)))?;
+            }
+            Stmt::Return(value) => {
+                let value = match value {
+                    Some(expr) => self.evaluate(expr)?,
+                    None => Value::None,
+                };
+                return Err(Interrupt::Return(value));
+            }
+        }
+        return Ok(());
+    }
+
+    pub fn execute_block(
+        &mut self,
+        statements: &[Stmt],
+        environment: Rc<RefCell<Environment>>,
+    ) -> Result<(), Interrupt> {
+        let previous = std::mem::replace(&mut self.environment, environment);
+        let result = statements
+            .iter()
+            .try_for_each(|statement| self.execute(statement));
+        self.environment = previous;
+        return result;
+    }
+
+    // Statements can only nest as deeply as the source does, so recursion
+    // that runs away always passes through here.
+    fn evaluate(&mut self, expr: &Expr) -> Result<Value, Error> {
+        if stack_position().abs_diff(self.stack_start) > self.stack_limit {
+            return Err(Self::stack_overflow(
+                &expr.span,
+                "expressions and calls are nested too deeply for the available stack",
+            ));
+        }
+        match &expr.kind {
+            ExprKind::Literal(literal) => {
+                return Ok(literal.clone());
             }
 
-            Expr::Grouping(expr) => {
+            ExprKind::Grouping(expr) => {
                 return self.evaluate(expr);
             }
 
-            Expr::Unary(token, expr) => {
+            ExprKind::Variable(name) => {
+                return self.environment.borrow().get(name);
+            }
+
+            ExprKind::Assign(name, expr) => {
+                let value = self.evaluate(expr)?;
+                self.environment.borrow_mut().assign(name, value.clone())?;
+                return Ok(value);
+            }
+
+            ExprKind::Unary(token, expr) => {
                 let right = self.evaluate(expr)?;
                 match token.token_type {
-                    TokenType::BANG => Ok(Literal::Bool(!Self::is_true(right))),
+                    TokenType::BANG => Ok(Value::Bool(!Self::is_true(&right))),
                     TokenType::MINUS => match right {
-                        Literal::Float(val) => Ok(Literal::Float(-val)),
-                        Literal::Int(val) => Ok(Literal::Int(-val)),
+                        Value::Float(val) => Ok(Value::Float(-val)),
+                        Value::Int(val) => match val.checked_neg() {
+                            Some(negated) => Ok(Value::Int(negated)),
+                            None => Self::map_operator_result(
+                                Value::from_bigint(BigInt::from(val).neg()),
+                                token,
+                            ),
+                        },
+                        Value::BigInt(val) => {
+                            Self::map_operator_result(Value::from_bigint(val.neg()), token)
+                        }
                         _ => Err(Error::new(
-                            token.line,
+                            "R017",
+                            token.span.clone(),
                             "Operand must be number".to_string(),
                             "".to_string(),
                         )),
                     },
                     _ => {
-                        return Ok(Literal::None);
+                        return Ok(Value::None);
                     }
                 }
             }
-            Expr::Binary(expr_left, token, expr_right) => {
+            ExprKind::Binary(expr_left, token, expr_right) => {
                 let left = self.evaluate(expr_left)?;
                 let right = self.evaluate(expr_right)?;
                 match token.token_type {
@@ -73,7 +408,10 @@ impl<'a> Interpreter<'a> {
                     TokenType::XOR => Self::map_operator_result(left.xor(right), token),
                     TokenType::PIPE => Self::map_operator_result(left.bit_or(right), token),
                     TokenType::PLUS => Self::map_operator_result(left.add(right), token),
-                    TokenType::EQUALEQUAL => Self::map_operator_result(left.equal_to(right), token),
+                    TokenType::EQUALEQUAL => Ok(Value::Bool(left.equals(&right))),
+                    TokenType::IN => {
+                        Self::map_operator_result(right.contains(&left).map(Value::Bool), token)
+                    }
                     TokenType::GREATER => Self::map_operator_result(left.greater(right), token),
                     TokenType::GREATEREQUAL => {
                         Self::map_operator_result(left.greater_or_equal(right), token)
@@ -82,37 +420,307 @@ impl<'a> Interpreter<'a> {
                     TokenType::LESSEQUAL => {
                         Self::map_operator_result(left.lesser_or_equal(right), token)
                     }
-                    TokenType::BANGEQUAL => Self::map_operator_result(left.not_equal(right), token),
+                    TokenType::BANGEQUAL => Ok(Value::Bool(!left.equals(&right))),
+                    _ => {
+                        return Ok(Value::None);
+                    }
+                }
+            }
+            ExprKind::Logical(expr_left, token, expr_right) => {
+                let left = self.evaluate(expr_left)?;
+                let is_left_true = Self::is_true(&left);
+                match token.token_type {
+                    TokenType::OR if is_left_true => Ok(left),
+                    TokenType::AND if !is_left_true => Ok(left),
+                    _ => self.evaluate(expr_right),
+                }
+            }
+            ExprKind::Call(callee_expr, paren, arguments) => {
+                let callee = self.evaluate(callee_expr)?;
+                let mut values = vec![];
+                for argument in arguments {
+                    values.push(self.evaluate(argument)?);
+                }
+                let function: &dyn Callable = match &callee {
+                    Value::Function(function) => function.as_ref(),
+                    Value::Class(class) => class,
+                    Value::Native(function) => function.as_ref(),
                     _ => {
-                        return Ok(Literal::None);
+                        return Err(Error::new(
+                            "R005",
+                            paren.span.clone(),
+                            "Can only call functions and classes".to_string(),
+                            "".to_string(),
+                        ))
                     }
+                };
+                if values.len() != function.arity() {
+                    return Err(Error::new(
+                        "R006",
+                        paren.span.clone(),
+                        format!(
+                            "Expected {} arguments but got {}",
+                            function.arity(),
+                            values.len()
+                        ),
+                        "".to_string(),
+                    )
+                    .with_label(
+                        callee_expr.span.clone(),
+                        &format!("this takes {} arguments", function.arity()),
+                    ));
+                }
+                if self.call_depth >= MAX_CALL_DEPTH {
+                    return Err(Self::stack_overflow(
+                        &paren.span,
+                        &format!(
+                            "calls can be nested at most {} deep; check for unbounded recursion",
+                            MAX_CALL_DEPTH
+                        ),
+                    ));
+                }
+                self.call_depth += 1;
+                let result = function.call(self, values, paren);
+                self.call_depth -= 1;
+                return result;
+            }
+            ExprKind::Get(object, name) => match self.evaluate(object)? {
+                Value::Instance(instance) => Instance::get(&instance, name),
+                _ => Err(Error::new(
+                    "R007",
+                    name.span.clone(),
+                    "Only instances have properties".to_string(),
+                    format!("at '{}'", name.lexeme),
+                )),
+            },
+            ExprKind::Set(object, name, value) => {
+                let Value::Instance(instance) = self.evaluate(object)? else {
+                    return Err(Error::new(
+                        "R007",
+                        name.span.clone(),
+                        "Only instances have fields".to_string(),
+                        format!("at '{}'", name.lexeme),
+                    ));
+                };
+                let value = self.evaluate(value)?;
+                instance.borrow_mut().set(name, value.clone());
+                return Ok(value);
+            }
+            ExprKind::This(keyword) => match self.environment.borrow().lookup("this") {
+                Some(instance) => Ok(instance),
+                None => Err(Error::new(
+                    "R022",
+                    keyword.span.clone(),
+                    "Can't use 'this' outside of a method".to_string(),
+                    "at 'this'".to_string(),
+                )),
+            },
+            ExprKind::Super(keyword, method) => {
+                let superclass = self.environment.borrow().lookup("super");
+                let instance = self.environment.borrow().lookup("this");
+                let (Some(Value::Class(superclass)), Some(instance)) = (superclass, instance)
+                else {
+                    return Err(Error::new(
+                        "R009",
+                        keyword.span.clone(),
+                        "Can't use 'super' outside of a subclass method".to_string(),
+                        "at 'super'".to_string(),
+                    ));
+                };
+                match superclass.find_method(&method.lexeme) {
+                    Some(function) => Ok(Value::Function(Rc::new(function.bind(instance)))),
+                    None => Err(Error::new(
+                        "R008",
+                        method.span.clone(),
+                        format!("Undefined property '{}'", method.lexeme),
+                        format!("at '{}'", method.lexeme),
+                    )),
                 }
             }
-            Expr::Ternary(left, mid, right) => {
+            ExprKind::List(items) => {
+                let mut values = vec![];
+                for item in items {
+                    values.push(self.evaluate(item)?);
+                }
+                return Ok(Value::new_list(values));
+            }
+            ExprKind::Map(brace, entries) => {
+                let mut map = Map::new();
+                for (key, value) in entries {
+                    let key = self.evaluate(key)?;
+                    let key = Self::map_operator_result(MapKey::from_value(&key), brace)?;
+                    let value = self.evaluate(value)?;
+                    map.insert(key, value);
+                }
+                return Ok(Value::new_map(map));
+            }
+            ExprKind::Interpolation(parts) => {
+                let mut value = String::new();
+                for part in parts {
+                    value += &self.evaluate(part)?.to_string();
+                }
+                return Ok(Value::String(value));
+            }
+            ExprKind::Index(object, bracket, index) => {
+                let object = self.evaluate(object)?;
+                let index = self.evaluate(index)?;
+                match &object {
+                    Value::List(items) => {
+                        let items = items.borrow();
+                        let position = Self::list_position(items.len(), &index, bracket)?;
+                        return Ok(items[position].clone());
+                    }
+                    Value::Map(map) => {
+                        let key = Self::map_operator_result(MapKey::from_value(&index), bracket)?;
+                        match map.borrow().get(&key) {
+                            Some(value) => Ok(value.clone()),
+                            None => Err(Self::missing_key(&index, bracket)),
+                        }
+                    }
+                    _ => Err(Self::not_indexable(&object, bracket)),
+                }
+            }
+            ExprKind::SetIndex(object, bracket, index, value) => {
+                let object = self.evaluate(object)?;
+                let index = self.evaluate(index)?;
+                let value = self.evaluate(value)?;
+                match &object {
+                    Value::List(items) => {
+                        let mut items = items.borrow_mut();
+                        let position = Self::list_position(items.len(), &index, bracket)?;
+                        items[position] = value.clone();
+                    }
+                    Value::Map(map) => {
+                        let key = Self::map_operator_result(MapKey::from_value(&index), bracket)?;
+                        map.borrow_mut().insert(key, value.clone());
+                    }
+                    _ => return Err(Self::not_indexable(&object, bracket)),
+                }
+                return Ok(value);
+            }
+            ExprKind::Slice(object, bracket, start, end) => {
+                let object = self.evaluate(object)?;
+                let start = match start {
+                    Some(start) => Some(self.evaluate(start)?),
+                    None => None,
+                };
+                let end = match end {
+                    Some(end) => Some(self.evaluate(end)?),
+                    None => None,
+                };
+                let Value::List(items) = object else {
+                    return Err(Self::not_indexable(&object, bracket));
+                };
+                let items = items.borrow();
+                let start = Self::slice_bound(items.len(), start, 0, bracket)?;
+                let end = Self::slice_bound(items.len(), end, items.len(), bracket)?;
+                let slice = if start < end {
+                    items[start..end].to_vec()
+                } else {
+                    vec![]
+                };
+                return Ok(Value::new_list(slice));
+            }
+            ExprKind::Ternary(left, mid, right) => {
                 let left = self.evaluate(left)?;
                 let mid = self.evaluate(mid)?;
                 let right = self.evaluate(right)?;
-                if Self::is_true(left) {
+                if Self::is_true(&left) {
                     Ok(mid)
                 } else {
                     Ok(right)
                 }
             }
-            _ => Ok(Literal::Bool(false)),
         }
     }
-    fn is_true(literal: Literal) -> bool {
+    fn is_true(literal: &Value) -> bool {
         match literal {
-            Literal::None => false,
-            Literal::Bool(val) => val,
+            Value::None => false,
+            Value::Bool(val) => *val,
             _ => true,
         }
     }
 
-    fn map_operator_result(
-        res: Result<Literal, ErrorMessage>,
-        token: Token,
-    ) -> Result<Literal, Error> {
-        res.map_err(|err| Error::new(token.line, err.message, "".to_string()))
+    fn list_index(index: &Value, bracket: &Token) -> Result<i64, Error> {
+        match index {
+            Value::Int(val) => Ok(*val),
+            // No list is this long, so the bounds check rejects it.
+            Value::BigInt(val) if val.is_negative() => Ok(i64::MIN),
+            Value::BigInt(_) => Ok(i64::MAX),
+            _ => Err(Error::new(
+                "R010",
+                bracket.span.clone(),
+                format!("List index must be an integer, not {}", index.describe()),
+                "".to_string(),
+            )),
+        }
+    }
+
+    // Negative indices count back from the end of the list.
+    fn list_position(len: usize, index: &Value, bracket: &Token) -> Result<usize, Error> {
+        let offset = Self::list_index(index, bracket)?;
+        let position = if offset < 0 { offset + len as i64 } else { offset };
+        if position < 0 || position >= len as i64 {
+            return Err(Error::new(
+                "R011",
+                bracket.span.clone(),
+                format!("Index {} out of range for list of length {}", index, len),
+                "".to_string(),
+            ));
+        }
+        return Ok(position as usize);
+    }
+
+    // Slice bounds are clamped to the list instead of raising errors.
+    fn slice_bound(
+        len: usize,
+        bound: Option<Value>,
+        default: usize,
+        bracket: &Token,
+    ) -> Result<usize, Error> {
+        let Some(bound) = bound else {
+            return Ok(default);
+        };
+        let index = Self::list_index(&bound, bracket)?;
+        let position = if index < 0 { index + len as i64 } else { index };
+        return Ok(position.clamp(0, len as i64) as usize);
+    }
+
+    fn stack_overflow(span: &Span, help: &str) -> Error {
+        Error::new(
+            "R015",
+            span.clone(),
+            "Stack overflow".to_string(),
+            "".to_string(),
+        )
+        .with_help(help)
     }
+
+    fn missing_key(key: &Value, bracket: &Token) -> Error {
+        Error::new(
+            "R012",
+            bracket.span.clone(),
+            format!("Key {} not found in map", key.describe()),
+            "".to_string(),
+        )
+    }
+
+    fn not_indexable(object: &Value, bracket: &Token) -> Error {
+        Error::new(
+            "R013",
+            bracket.span.clone(),
+            format!("{} is not indexable", object.type_name()),
+            "".to_string(),
+        )
+    }
+
+    fn map_operator_result<T>(res: Result<T, ErrorMessage>, token: &Token) -> Result<T, Error> {
+        res.map_err(|err| Error::new(err.code, token.span.clone(), err.message, "".to_string()))
+    }
+}
+
+// The address of a local in the current frame, to measure stack use by.
+fn stack_position() -> usize {
+    let marker = 0u8;
+    std::hint::black_box(&marker) as *const u8 as usize
 }
@@ -1,34 +1,54 @@
-use std::{
-    collections::HashMap,
-    iter::{Enumerate, Peekable},
-    str::Chars,
+use std::{collections::HashMap, iter::Peekable, rc::Rc, str::CharIndices};
+
+use crate::{
+    bigint::{digits_may_fit, BigInt, MAX_BITS},
+    error::{Error, ErrorManager, UNTERMINATED_INTERPOLATION, UNTERMINATED_STRING},
+    span::Span,
+    token::Token,
+    token_type::TokenType,
+    value::Value,
+    xid,
 };
 
-use crate::{error::ErrorManager, literal::Literal, token::Token, token_type::TokenType};
+// A `${` inside a string that is still being lexed as ordinary tokens.
+// `depth` counts unmatched '{' so the '}' closing the interpolation can be
+// told apart from map literals inside it.
+struct Interpolation {
+    depth: usize,
+    triple: bool,
+    parts: Vec<usize>,
+}
 
+// All positions (`start`, `current`, `line_start`) are byte offsets into
+// `source`; `current` is one past the last consumed character.
 pub struct Lexer<'a> {
-    source: &'a String,
-    source_vector: Vec<char>,
-    iter: Peekable<Enumerate<Chars<'a>>>,
+    source: &'a str,
+    file: Rc<str>,
+    iter: Peekable<CharIndices<'a>>,
     tokens: Vec<Token>,
     start: usize,
     current: usize,
     line: usize,
+    line_start: usize,
+    start_line: usize,
+    start_column: usize,
+    interpolations: Vec<Interpolation>,
     keywords: HashMap<&'a str, TokenType>,
     error_manager: &'a mut ErrorManager,
 }
 
 impl<'a> Lexer<'a> {
-    pub fn new(source: &'a String, error_manager: &'a mut ErrorManager) -> Self {
-        let iter = source.chars().enumerate().peekable();
-        let source_vector: Vec<char> = source.clone().chars().collect();
+    pub fn new(source: &'a str, file: &str, error_manager: &'a mut ErrorManager) -> Self {
+        let iter = source.char_indices().peekable();
         let mut keywords: HashMap<&str, TokenType> = HashMap::new();
         keywords.insert("and", TokenType::AND);
         keywords.insert("or", TokenType::OR);
         keywords.insert("class", TokenType::CLASS);
+        keywords.insert("del", TokenType::DEL);
         keywords.insert("else", TokenType::ELSE);
         keywords.insert("false", TokenType::FALSE);
         keywords.insert("if", TokenType::IF);
+        keywords.insert("in", TokenType::IN);
         keywords.insert("for", TokenType::FOR);
         keywords.insert("fun", TokenType::FUN);
         keywords.insert("print", TokenType::PRINT);
@@ -41,140 +61,361 @@ impl<'a> Lexer<'a> {
         keywords.insert("while", TokenType::WHILE);
         Lexer {
             source,
+            file: error_manager.add_source(file, source),
             iter,
             tokens: vec![],
             start: 0,
             current: 0,
             line: 1,
+            line_start: 0,
+            start_line: 1,
+            start_column: 1,
+            interpolations: vec![],
             keywords,
             error_manager,
-            source_vector,
         }
     }
 
-    pub fn scan_tokens(&mut self)->Vec<Token> {
+    pub fn scan_tokens(&mut self) -> Vec<Token> {
         while let Some((index, _)) = self.iter.peek() {
             self.start = *index;
+            self.start_line = self.line;
+            self.start_column = self.column(self.start);
             self.scan_token();
         }
+        if !self.interpolations.is_empty() {
+            self.error_manager.add_lex_error(Error::new(
+                UNTERMINATED_INTERPOLATION,
+                self.span_at(self.current, self.line, self.column(self.current)),
+                "Unterminated string interpolation".to_string(),
+                "".to_string(),
+            ));
+        }
+        self.start = self.current;
+        self.start_line = self.line;
+        self.start_column = self.column(self.start);
         self.add_token(TokenType::EOF, None);
         return self.tokens.clone();
     }
 
     fn does_match(&mut self, c: char) -> bool {
-        if let Some((index, curr_char)) = self.iter.peek() {
-            if *curr_char == c {
-                let (index, _) = self.iter.next().unwrap();
-                self.current = index;
-                return true;
-            } else {
-                return false;
+        if self.peek_char() == Some(c) {
+            self.advance_char();
+            return true;
+        }
+        return false;
+    }
+
+    fn peek_char(&mut self) -> Option<char> {
+        self.iter.peek().map(|(_, c)| *c)
+    }
+
+    // The character after the one `peek_char` returns.
+    fn peek_next(&self) -> Option<char> {
+        self.source[self.current..].chars().nth(1)
+    }
+
+    fn advance_char(&mut self) -> Option<char> {
+        let (index, c) = self.iter.next()?;
+        self.current = index + c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.line_start = self.current;
+        }
+        Some(c)
+    }
+
+    // One-based column of the character starting at byte `index`, counted in
+    // characters rather than bytes.
+    fn column(&self, index: usize) -> usize {
+        self.source[self.line_start..index].chars().count() + 1
+    }
+
+    // Scans a string whose opening '"' has been consumed. Raw strings keep
+    // backslashes as written; `"""` strings may span lines and have their
+    // common indentation stripped.
+    fn match_string(&mut self, raw: bool) {
+        let triple = self.peek_char() == Some('"') && self.peek_next() == Some('"');
+        if triple {
+            self.advance_char();
+            self.advance_char();
+        }
+        self.string_body(raw, triple, false, vec![]);
+    }
+
+    // Each `${` ends the current piece with an INTERPOLATION token and hands
+    // back to `scan_token` for the embedded expression; the matching '}'
+    // resumes here with `interpolated` set. The final piece is then an
+    // INTERPOLATIONEND token, so the parser can't mistake it for a separate
+    // string. `parts` holds the token indices of the earlier pieces of a
+    // triple-quoted string so they can be dedented together.
+    fn string_body(&mut self, raw: bool, triple: bool, interpolated: bool, mut parts: Vec<usize>) {
+        let mut value = String::new();
+        loop {
+            let Some(c) = self.advance_char() else {
+                self.error_manager.add_lex_error(Error::new(
+                    UNTERMINATED_STRING,
+                    self.token_span(),
+                    "Unterminated String".to_string(),
+                    "".to_string(),
+                ));
+                return;
+            };
+            match c {
+                '"' if !triple => break,
+                '"' if self.peek_char() == Some('"') && self.peek_next() == Some('"') => {
+                    self.advance_char();
+                    self.advance_char();
+                    break;
+                }
+                '\\' if !raw => {
+                    if let Some(escaped) = self.escape() {
+                        value.push(escaped);
+                    }
+                }
+                '$' if !raw && self.peek_char() == Some('{') => {
+                    self.advance_char();
+                    if triple {
+                        parts.push(self.tokens.len());
+                    }
+                    self.add_token(TokenType::INTERPOLATION, Some(Value::String(value)));
+                    self.interpolations.push(Interpolation {
+                        depth: 0,
+                        triple,
+                        parts,
+                    });
+                    return;
+                }
+                _ => value.push(c),
             }
+        }
+        let end = if interpolated {
+            TokenType::INTERPOLATIONEND
         } else {
-            return false;
+            TokenType::STRING
+        };
+        if !triple {
+            self.add_token(end, Some(Value::String(value)));
+            return;
+        }
+        let mut pieces: Vec<String> = parts
+            .iter()
+            .map(|&part| match &self.tokens[part].literal {
+                Some(Value::String(piece)) => piece.clone(),
+                _ => String::new(),
+            })
+            .collect();
+        pieces.push(value);
+        let mut pieces = Self::dedent_pieces(&pieces);
+        let value = pieces.pop().unwrap_or_default();
+        for (part, piece) in parts.into_iter().zip(pieces) {
+            self.tokens[part].literal = Some(Value::String(piece));
         }
+        self.add_token(end, Some(Value::String(value)));
     }
 
-    fn match_string(&mut self) {
-        while let Some((index, curr_char)) = self.iter.peek() {
-            if *curr_char == '"' {
-                let string_literal = self.source[(self.start + 1)..(*index)].to_string();
-                self.current = *index;
-                self.add_token(TokenType::STRING, Some(Literal::String(string_literal)));
-                self.iter.next().unwrap();
-                return;
-            } else if *curr_char == '\n' {
-                self.line += 1;
+    // Dedents the pieces of an interpolated string as one text by joining them
+    // with a placeholder character that none of them contain.
+    fn dedent_pieces(pieces: &[String]) -> Vec<String> {
+        let placeholder = ('\u{E000}'..='\u{F8FF}')
+            .find(|c| pieces.iter().all(|piece| !piece.contains(*c)))
+            .unwrap_or('\u{F8FF}');
+        Self::dedent(&pieces.join(&placeholder.to_string()))
+            .split(placeholder)
+            .map(String::from)
+            .collect()
+    }
+
+    fn escape(&mut self) -> Option<char> {
+        let escape_start = self.current - '\\'.len_utf8();
+        // Taken before advancing: an escaped newline moves `line_start` past
+        // `escape_start`.
+        let (line, column) = (self.line, self.column(escape_start));
+        let next = self.advance_char();
+        let escaped = match next {
+            Some('n') => Some('\n'),
+            Some('t') => Some('\t'),
+            Some('r') => Some('\r'),
+            Some('0') => Some('\0'),
+            Some('\\') => Some('\\'),
+            Some('"') => Some('"'),
+            Some('$') => Some('$'),
+            Some('u') => return self.unicode_escape(escape_start, line, column),
+            _ => None,
+        };
+        if escaped.is_none() {
+            let message = match next {
+                Some('\n') | Some('\r') => "A '\\' can't end a line in a string".to_string(),
+                _ => format!("Unknown escape sequence '\\{}'", next.unwrap_or(' ')),
+            };
+            let error = Error::new(
+                "L004",
+                self.span_at(escape_start, line, column),
+                message,
+                format!("at column {}", column),
+            )
+            .with_help("valid escapes are \\n \\t \\r \\0 \\\\ \\\" \\$ and \\u{XXXX}");
+            self.error_manager.add_lex_error(error);
+        }
+        escaped
+    }
+
+    // `\u{XXXX}` with one to six hex digits naming a Unicode scalar value.
+    fn unicode_escape(&mut self, escape_start: usize, line: usize, column: usize) -> Option<char> {
+        let mut digits = String::new();
+        let mut closed = false;
+        if self.peek_char() == Some('{') {
+            self.advance_char();
+            while let Some(c) = self.peek_char() {
+                if c == '}' {
+                    self.advance_char();
+                    closed = true;
+                    break;
+                } else if c.is_ascii_hexdigit() && digits.len() < 6 {
+                    self.advance_char();
+                    digits.push(c);
+                } else {
+                    break;
+                }
             }
-            let (index, _) = self.iter.next().unwrap();
-            self.current = index;
         }
-        (*self.error_manager).add_error(
-            self.line,
-            "Unterminated String".to_string(),
-            "".to_string(),
-        );
+        let escaped = u32::from_str_radix(&digits, 16)
+            .ok()
+            .filter(|_| closed)
+            .and_then(char::from_u32);
+        if escaped.is_none() {
+            self.error_manager.add_lex_error(Error::new(
+                "L005",
+                self.span_at(escape_start, line, column),
+                "Invalid unicode escape, expected '\\u{XXXX}'".to_string(),
+                format!("at column {}", column),
+            ));
+        }
+        escaped
+    }
+
+    fn dedent(value: &str) -> String {
+        let value = value.strip_prefix('\n').unwrap_or(value);
+        let mut lines: Vec<&str> = value.split('\n').collect();
+        if lines.len() > 1 && lines.last().unwrap().trim().is_empty() {
+            lines.pop();
+        }
+        let indent = lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+            .min()
+            .unwrap_or(0);
+        lines
+            .iter()
+            .map(|line| line.get(indent..).unwrap_or(""))
+            .collect::<Vec<&str>>()
+            .join("\n")
     }
 
     fn is_digit(c: char) -> bool {
-        c >= '0' && c <= '9'
+        c.is_ascii_digit()
     }
 
-    fn peek_next(&self, index: usize) -> char {
-        if index + 1 >= self.source_vector.len() {
-            return '\0';
+    fn advance_digits(&mut self) {
+        while let Some(c) = self.peek_char() {
+            if !Self::is_digit(c) {
+                break;
+            }
+            self.advance_char();
         }
-        return self.source_vector[index + 1];
     }
 
     fn number(&mut self) {
         let mut is_decimal = false;
-        while let Some((index, char)) = self.iter.peek() {
-            if Self::is_digit(*char) {
-                let (index, _) = self.iter.next().unwrap();
-                self.current = index;
-            } else {
-                break;
-            }
-        }
-        if let Some((index, char)) = self.iter.peek() {
-            let curr_index = *index;
-            if *char == '.' && Self::is_digit(self.peek_next(curr_index)) {
-                let (index, _) = self.iter.next().unwrap();
-                is_decimal = true;
-                self.current = index;
-                while let Some((index, char)) = self.iter.peek() {
-                    if Self::is_digit(*char) {
-                        let (index, _) = self.iter.next().unwrap();
-                        self.current = index;
-                    } else {
-                        break;
-                    }
-                }
-            }
+        self.advance_digits();
+        if self.peek_char() == Some('.') && self.peek_next().is_some_and(Self::is_digit) {
+            self.advance_char();
+            is_decimal = true;
+            self.advance_digits();
         }
         if is_decimal {
-            let decimal: f64 = self.source[self.start..=self.current].parse().unwrap();
-            self.add_token(TokenType::FLOAT, Some(Literal::Float(decimal)));
+            let decimal: f64 = self.source[self.start..self.current].parse().unwrap();
+            self.add_token(TokenType::FLOAT, Some(Value::Float(decimal)));
         } else {
-            let integer: i64 = self.source[self.start..=self.current].parse().unwrap();
-            self.add_token(TokenType::INTEGER, Some(Literal::Int(integer)));
+            let digits = &self.source[self.start..self.current];
+            let integer = match digits.parse::<i64>() {
+                Ok(integer) => Some(Value::Int(integer)),
+                Err(_) if digits_may_fit(digits.trim_start_matches('0').len()) => {
+                    Value::from_bigint(BigInt::parse(digits).unwrap()).ok()
+                }
+                Err(_) => None,
+            };
+            let integer = match integer {
+                Some(integer) => integer,
+                None => {
+                    self.error_manager.add_lex_error(
+                        Error::new(
+                            "L006",
+                            self.token_span(),
+                            "Integer literal is too large".to_string(),
+                            format!("{} digits", digits.len()),
+                        )
+                        .with_help(&format!("integers are limited to {} bits", MAX_BITS)),
+                    );
+                    // Keep a token in its place so the parser doesn't report
+                    // a missing expression as well; nothing runs anyway.
+                    Value::Int(0)
+                }
+            };
+            self.add_token(TokenType::INTEGER, Some(integer));
         }
     }
 
+    // Identifiers follow Unicode's XID_Start / XID_Continue, except that
+    // '_' may start one as well.
     fn is_alpha(c: char) -> bool {
-        (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || c == '_'
+        c.is_ascii_alphabetic() || c == '_' || (!c.is_ascii() && xid::is_xid_start(c))
     }
 
     fn is_alphanumeric(c: char) -> bool {
-        Self::is_digit(c) || Self::is_alpha(c)
+        c.is_ascii_alphanumeric() || c == '_' || (!c.is_ascii() && xid::is_xid_continue(c))
     }
 
     fn identifier(&mut self) {
-        while let Some((index, curr_char)) = self.iter.peek() {
-            if Self::is_alphanumeric(*curr_char) {
-                let (index, _) = self.iter.next().unwrap();
-                self.current = index;
-            } else {
-                let word = &self.source[self.start..=self.current];
-                if let Some(keyword_type) = self.keywords.get(word) {
-                    self.add_token(*keyword_type, None);
-                    return;
-                }
-                self.add_token(TokenType::IDENTIFIER, None);
-                return;
+        while let Some(c) = self.peek_char() {
+            if !Self::is_alphanumeric(c) {
+                break;
             }
+            self.advance_char();
         }
+        let word = &self.source[self.start..self.current];
+        if let Some(keyword_type) = self.keywords.get(word) {
+            self.add_token(*keyword_type, None);
+            return;
+        }
+        self.add_token(TokenType::IDENTIFIER, None);
     }
 
     fn scan_token(&mut self) {
-        if let Some((index, c)) = self.iter.next() {
-            self.current = index;
+        if let Some(c) = self.advance_char() {
             match c {
                 '(' => self.add_token(TokenType::LEFTPAREN, None),
                 ')' => self.add_token(TokenType::RIGHTPAREN, None),
-                '{' => self.add_token(TokenType::LEFTBRACE, None),
-                '}' => self.add_token(TokenType::RIGHTBRACE, None),
+                '{' => {
+                    if let Some(interpolation) = self.interpolations.last_mut() {
+                        interpolation.depth += 1;
+                    }
+                    self.add_token(TokenType::LEFTBRACE, None)
+                }
+                '}' => match self.interpolations.last_mut() {
+                    Some(interpolation) if interpolation.depth == 0 => {
+                        let interpolation = self.interpolations.pop().unwrap();
+                        self.string_body(false, interpolation.triple, true, interpolation.parts);
+                    }
+                    Some(interpolation) => {
+                        interpolation.depth -= 1;
+                        self.add_token(TokenType::RIGHTBRACE, None)
+                    }
+                    None => self.add_token(TokenType::RIGHTBRACE, None),
+                },
+                '[' => self.add_token(TokenType::LEFTBRACKET, None),
+                ']' => self.add_token(TokenType::RIGHTBRACKET, None),
                 '.' => self.add_token(TokenType::DOT, None),
                 ',' => self.add_token(TokenType::COMMA, None),
                 '+' => self.add_token(TokenType::PLUS, None),
@@ -221,14 +462,8 @@ impl<'a> Lexer<'a> {
                 }
                 '/' => {
                     if self.does_match('/') {
-                        while let Some((_index, curr_char)) = self.iter.peek() {
-                            if *curr_char == '\n' {
-                                self.line += 1;
-                                break;
-                            } else {
-                                let (index, _) = self.iter.next().unwrap();
-                                self.current = index;
-                            }
+                        while self.peek_char().is_some_and(|c| c != '\n') {
+                            self.advance_char();
                         }
                     } else {
                         self.add_token(TokenType::SLASH, None);
@@ -237,32 +472,67 @@ impl<'a> Lexer<'a> {
                 ' ' => (),
                 '\t' => (),
                 '\r' => (),
-                '\n' => self.line += 1,
-                '"' => self.match_string(),
+                '\n' => (),
+                '"' => self.match_string(false),
+                'r' if self.peek_char() == Some('"') => {
+                    self.advance_char();
+                    self.match_string(true);
+                }
                 _ => {
                     if Self::is_digit(c) {
                         self.number();
                     } else if Self::is_alpha(c) {
                         self.identifier();
                     } else {
-                        self.error_manager.add_error(
-                            self.line,
+                        self.error_manager.add_lex_error(Error::new(
+                            "L001",
+                            self.token_span(),
                             "Unexpected token".to_string(),
-                            "".to_string(),
-                        );
+                            format!("at '{}'", c),
+                        ));
+                        self.add_token(TokenType::ERROR, None);
                     }
                 }
             }
         }
     }
-    fn add_token(&mut self, token_type: TokenType, literal: Option<Literal>) {
-        let lexeme: String = self.source[self.start..=self.current].to_string();
+    fn add_token(&mut self, token_type: TokenType, literal: Option<Value>) {
+        let lexeme: String = self.source[self.start..self.current].to_string();
         self.tokens
-            .push(Token::new(token_type, self.line, lexeme, literal));
+            .push(Token::new(token_type, self.token_span(), lexeme, literal));
+    }
+
+    fn token_span(&self) -> Span {
+        Span::new(
+            self.file.clone(),
+            self.start,
+            self.current,
+            self.start_line,
+            self.start_column,
+        )
+    }
+
+    // The span from byte `start`, at `line` and `column`, to the current
+    // position.
+    fn span_at(&self, start: usize, line: usize, column: usize) -> Span {
+        Span::new(self.file.clone(), start, self.current, line, column)
     }
-    pub fn print_tokens(&self) {
+    // One token per line: position, type, lexeme and any literal value.
+    pub fn format_tokens(&self) -> String {
+        let mut out = String::new();
         for token in self.tokens.iter() {
-            print!("{:?}", token);
+            let position = format!("{}:{}", token.span.line, token.span.column);
+            let token_type = format!("{:?}", token.token_type);
+            let line = match &token.literal {
+                Some(literal) => format!(
+                    "{:<8} {:<16} {:<16} {:?}",
+                    position, token_type, token.lexeme, literal
+                ),
+                None => format!("{:<8} {:<16} {}", position, token_type, token.lexeme),
+            };
+            out += line.trim_end();
+            out.push('\n');
         }
+        out
     }
 }
@@ -0,0 +1,33 @@
+#![allow(clippy::needless_return)]
+mod hiscript;
+mod token;
+mod token_type;
+mod value;
+mod bigint;
+mod map;
+mod lexer;
+mod xid;
+mod error;
+mod expr;
+mod span;
+mod stmt;
+mod parser;
+mod interpreter;
+mod callable;
+mod class;
+mod native;
+mod stdlib;
+mod ast_printer;
+mod engine;
+mod capabilities;
+
+pub use crate::{
+    ast_printer::AstFormat,
+    bigint::BigInt,
+    engine::Engine,
+    error::{Diagnostics, Error as Diagnostic, ErrorFormat, Label, Phase, PRINT_FAILED},
+    map::{Map, MapKey},
+    native::{FromValue, HostFn, IntoResult, IntoValue, NativeFunction},
+    span::Span,
+    value::{Value, WideInt},
+};
@@ -0,0 +1,321 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, stdin, stdout, IsTerminal, Read, Write},
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+// Lines kept from the history file between sessions.
+const HISTORY_LIMIT: usize = 1000;
+
+pub enum ReadLine {
+    Line(String),
+    // Ctrl-C: the user abandoned what they were typing.
+    Interrupted,
+    Eof,
+}
+
+enum Key {
+    Char(char),
+    Enter,
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Home,
+    End,
+    Up,
+    Down,
+    KillToStart,
+    KillToEnd,
+    Interrupt,
+    Eof,
+    // Standard input itself ended.
+    Closed,
+    Ignored,
+}
+
+// A minimal line editor: cursor movement, deletion and history recall with
+// the arrow keys. The terminal is switched to raw mode through `stty` only
+// while a line is being read; without a terminal lines are read as they are.
+pub struct LineEditor {
+    history: Vec<String>,
+    history_file: Option<PathBuf>,
+}
+
+impl LineEditor {
+    pub fn new(history_file: Option<PathBuf>) -> Self {
+        let mut history: Vec<String> = vec![];
+        if let Some(text) = history_file
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+        {
+            history = text.lines().map(|line| line.to_string()).collect();
+            let excess = history.len().saturating_sub(HISTORY_LIMIT);
+            history.drain(..excess);
+        }
+        LineEditor {
+            history,
+            history_file,
+        }
+    }
+
+    // `$HISCRIPT_HISTORY`, or `.hiscript_history` in the home directory.
+    pub fn default_history_file() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os("HISCRIPT_HISTORY") {
+            return Some(PathBuf::from(path));
+        }
+        let home = std::env::var_os("HOME")?;
+        return Some(PathBuf::from(home).join(".hiscript_history"));
+    }
+
+    pub fn add_history(&mut self, line: &str) {
+        if line.trim().is_empty() || self.history.last().map(|last| last.as_str()) == Some(line) {
+            return;
+        }
+        self.history.push(line.to_string());
+        if let Some(path) = &self.history_file {
+            // History is a convenience, so failing to save it isn't an error.
+            if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+                let _ = writeln!(file, "{}", line);
+            }
+        }
+    }
+
+    pub fn read_line(&mut self, prompt: &str) -> io::Result<ReadLine> {
+        if stdin().is_terminal() {
+            if let Some(raw_mode) = RawMode::enable() {
+                let result = self.edit(prompt);
+                drop(raw_mode);
+                return result;
+            }
+        }
+        print!("{}", prompt);
+        stdout().flush()?;
+        let mut line = String::new();
+        if stdin().read_line(&mut line)? == 0 {
+            return Ok(ReadLine::Eof);
+        }
+        let line = line.strip_suffix('\n').unwrap_or(&line);
+        return Ok(ReadLine::Line(
+            line.strip_suffix('\r').unwrap_or(line).to_string(),
+        ));
+    }
+
+    fn edit(&mut self, prompt: &str) -> io::Result<ReadLine> {
+        let mut line: Vec<char> = vec![];
+        let mut cursor = 0;
+        // Index of the history entry being shown; `history.len()` is the
+        // line being typed, which is kept in `draft` while browsing.
+        let mut recall = self.history.len();
+        let mut draft: Vec<char> = vec![];
+        Self::refresh(prompt, &line, cursor)?;
+        loop {
+            match Self::read_key()? {
+                Key::Char(c) => {
+                    line.insert(cursor, c);
+                    cursor += 1;
+                }
+                Key::Enter => {
+                    print!("\r\n");
+                    stdout().flush()?;
+                    return Ok(ReadLine::Line(line.into_iter().collect()));
+                }
+                Key::Backspace => {
+                    if cursor > 0 {
+                        cursor -= 1;
+                        line.remove(cursor);
+                    }
+                }
+                Key::Delete => {
+                    if cursor < line.len() {
+                        line.remove(cursor);
+                    }
+                }
+                Key::Left => cursor = cursor.saturating_sub(1),
+                Key::Right => cursor = (cursor + 1).min(line.len()),
+                Key::Home => cursor = 0,
+                Key::End => cursor = line.len(),
+                Key::Up => {
+                    if recall > 0 {
+                        if recall == self.history.len() {
+                            draft = line.clone();
+                        }
+                        recall -= 1;
+                        line = self.history[recall].chars().collect();
+                        cursor = line.len();
+                    }
+                }
+                Key::Down => {
+                    if recall < self.history.len() {
+                        recall += 1;
+                        line = match self.history.get(recall) {
+                            Some(entry) => entry.chars().collect(),
+                            None => draft.clone(),
+                        };
+                        cursor = line.len();
+                    }
+                }
+                Key::KillToStart => {
+                    line.drain(..cursor);
+                    cursor = 0;
+                }
+                Key::KillToEnd => line.truncate(cursor),
+                Key::Interrupt => {
+                    print!("^C\r\n");
+                    stdout().flush()?;
+                    return Ok(ReadLine::Interrupted);
+                }
+                Key::Eof => {
+                    if line.is_empty() {
+                        print!("\r\n");
+                        stdout().flush()?;
+                        return Ok(ReadLine::Eof);
+                    }
+                    if cursor < line.len() {
+                        line.remove(cursor);
+                    }
+                }
+                Key::Closed => return Ok(ReadLine::Eof),
+                Key::Ignored => continue,
+            }
+            Self::refresh(prompt, &line, cursor)?;
+        }
+    }
+
+    fn refresh(prompt: &str, line: &[char], cursor: usize) -> io::Result<()> {
+        let text: String = line.iter().collect();
+        let mut out = stdout();
+        write!(out, "\r{}{}\x1b[K", prompt, text)?;
+        if cursor < line.len() {
+            write!(out, "\x1b[{}D", line.len() - cursor)?;
+        }
+        out.flush()
+    }
+
+    fn read_byte() -> io::Result<Option<u8>> {
+        let mut byte = [0u8];
+        if stdin().read(&mut byte)? == 0 {
+            return Ok(None);
+        }
+        return Ok(Some(byte[0]));
+    }
+
+    fn read_key() -> io::Result<Key> {
+        let Some(byte) = Self::read_byte()? else {
+            return Ok(Key::Closed);
+        };
+        let key = match byte {
+            b'\r' | b'\n' => Key::Enter,
+            0x7f | 0x08 => Key::Backspace,
+            0x01 => Key::Home,
+            0x02 => Key::Left,
+            0x03 => Key::Interrupt,
+            0x04 => Key::Eof,
+            0x05 => Key::End,
+            0x06 => Key::Right,
+            0x0b => Key::KillToEnd,
+            0x0e => Key::Down,
+            0x10 => Key::Up,
+            0x15 => Key::KillToStart,
+            0x1b => Self::read_escape()?,
+            byte if byte < 0x20 => Key::Ignored,
+            byte => Self::read_char(byte)?,
+        };
+        return Ok(key);
+    }
+
+    // Arrow, Home, End and Delete keys arrive as `ESC [ ...` or `ESC O ...`.
+    fn read_escape() -> io::Result<Key> {
+        if !matches!(Self::read_byte()?, Some(b'[') | Some(b'O')) {
+            return Ok(Key::Ignored);
+        }
+        let key = match Self::read_byte()? {
+            Some(b'A') => Key::Up,
+            Some(b'B') => Key::Down,
+            Some(b'C') => Key::Right,
+            Some(b'D') => Key::Left,
+            Some(b'H') => Key::Home,
+            Some(b'F') => Key::End,
+            Some(digit @ b'0'..=b'9') => {
+                let mut code = vec![digit];
+                while let Some(byte) = Self::read_byte()? {
+                    if byte == b'~' || !byte.is_ascii_digit() {
+                        break;
+                    }
+                    code.push(byte);
+                }
+                match code.as_slice() {
+                    b"1" | b"7" => Key::Home,
+                    b"4" | b"8" => Key::End,
+                    b"3" => Key::Delete,
+                    _ => Key::Ignored,
+                }
+            }
+            _ => Key::Ignored,
+        };
+        return Ok(key);
+    }
+
+    // Collects the continuation bytes of a UTF-8 encoded character.
+    fn read_char(first: u8) -> io::Result<Key> {
+        let length = match first {
+            0xf0..=0xf7 => 4,
+            0xe0..=0xef => 3,
+            0xc0..=0xdf => 2,
+            _ => 1,
+        };
+        let mut bytes = vec![first];
+        while bytes.len() < length {
+            match Self::read_byte()? {
+                Some(byte) => bytes.push(byte),
+                None => break,
+            }
+        }
+        let key = match std::str::from_utf8(&bytes)
+            .ok()
+            .and_then(|text| text.chars().next())
+        {
+            Some(c) => Key::Char(c),
+            None => Key::Ignored,
+        };
+        return Ok(key);
+    }
+}
+
+// Puts the terminal into raw mode and restores its previous settings on drop.
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> Option<RawMode> {
+        let output = Command::new("stty")
+            .arg("-g")
+            .stdin(Stdio::inherit())
+            .output()
+            .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let saved = String::from_utf8(output.stdout).ok()?.trim().to_string();
+        let status = Command::new("stty")
+            .args(["-icanon", "-echo", "-isig", "-ixon", "min", "1"])
+            .stdin(Stdio::inherit())
+            .status()
+            .ok()?;
+        if !status.success() {
+            return None;
+        }
+        return Some(RawMode { saved });
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = Command::new("stty")
+            .arg(&self.saved)
+            .stdin(Stdio::inherit())
+            .status();
+    }
+}
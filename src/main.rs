@@ -1,25 +1,141 @@
-mod hiscript;
-mod token;
-mod token_type;
-mod literal;
-mod lexer;
-mod error;
-mod expr;
-mod parser;
-mod interpreter;
-use std::error::Error;
-
-use crate::hiscript::HiScript;
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = std::env::args().collect();
-    let interpreter = HiScript::new();
-    if args.len() > 2 {
-        println!("Usage: hiscript [script]");
-        return Err("".into());
-    } else if args.len() == 2 {
-        return interpreter.run_file(&args[1]);
+#![allow(clippy::needless_return)]
+mod cli;
+mod line_editor;
+mod repl;
+use std::{
+    fs,
+    io::{stdin, stdout, Read, Write},
+    process::ExitCode,
+    thread,
+};
+
+use hiscript::{Diagnostics, Engine, ErrorFormat, PRINT_FAILED};
+
+use crate::{
+    cli::{
+        Command, Input, Options, EX_DATAERR, EX_IOERR, EX_NOINPUT, EX_OK, EX_SOFTWARE, EX_USAGE,
+    },
+    repl::Repl,
+};
+
+// Scripts may nest calls as deep as the interpreter's limit allows, which
+// takes more stack than a main thread has in unoptimized builds. The engine
+// stops short of the end, leaving room for the frames between its checks.
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+const STACK_LIMIT: usize = STACK_SIZE - 32 * 1024 * 1024;
+
+fn main() -> ExitCode {
+    let status = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(cli_main)
+        .expect("failed to start the interpreter thread")
+        .join()
+        .unwrap_or(EX_SOFTWARE);
+    return ExitCode::from(status);
+}
+
+fn cli_main() -> u8 {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (input, options) = match cli::parse_args(args) {
+        Ok(Command::Run(input, options)) => (input, options),
+        Ok(Command::Help) => return write_stdout(&format!("{}\n", cli::help())),
+        Ok(Command::Version) => return write_stdout(&format!("{}\n", cli::version())),
+        Err(message) => {
+            eprintln!("{}\n{}", message, cli::USAGE);
+            return EX_USAGE;
+        }
+    };
+    let mut engine = Engine::new();
+    engine.set_stack_limit(STACK_LIMIT);
+    engine.set_args(&options.script_args);
+    if options.allow_stdio {
+        engine.allow_stdio();
+    }
+    for path in options.allow_fs.iter() {
+        if let Err(err) = engine.allow_fs(path) {
+            eprintln!("Cannot allow access to '{}': {}", path, err);
+            return EX_NOINPUT;
+        }
+    }
+    let status = match input {
+        Input::File(path) => match fs::read_to_string(&path) {
+            Ok(source) => run(&mut engine, &options, &source, &path),
+            Err(err) => {
+                eprintln!("Could not read '{}': {}", path, err);
+                EX_NOINPUT
+            }
+        },
+        Input::Eval(source) => run(&mut engine, &options, &source, "<eval>"),
+        Input::Stdin => {
+            let mut source = String::new();
+            match stdin().read_to_string(&mut source) {
+                Ok(_) => run(&mut engine, &options, &source, "<stdin>"),
+                Err(err) => {
+                    eprintln!("Could not read standard input: {}", err);
+                    EX_NOINPUT
+                }
+            }
+        }
+        Input::Prompt => Repl::new(engine, &options).run(),
+    };
+    return status;
+}
+
+// Runs, checks or dumps `source` as the options ask, reporting any errors,
+// and returns the exit status.
+fn run(engine: &mut Engine, options: &Options, source: &str, file: &str) -> u8 {
+    let result = if options.dump_tokens || options.dump_ast.is_some() {
+        match dump(engine, options, source, file) {
+            Ok(text) => return write_stdout(&text),
+            Err(diagnostics) => Err(diagnostics),
+        }
+    } else if options.check_only {
+        engine.check(source, file)
     } else {
-        return interpreter.run_prompt();
+        engine.run(source, file)
+    };
+    match result {
+        Ok(()) => {
+            if options.error_format == ErrorFormat::Json {
+                Diagnostics::default().report(ErrorFormat::Json);
+            }
+            return EX_OK;
+        }
+        Err(diagnostics) => {
+            diagnostics.report(options.error_format);
+            if diagnostics.has_compile_errors() {
+                return EX_DATAERR;
+            }
+            // `print` failed, most often because stdout was a pipe that
+            // has been closed.
+            if diagnostics.has_error_code(PRINT_FAILED) {
+                return EX_IOERR;
+            }
+            return EX_SOFTWARE;
+        }
+    }
+}
+
+fn dump(
+    engine: &mut Engine,
+    options: &Options,
+    source: &str,
+    file: &str,
+) -> Result<String, Diagnostics> {
+    let mut text = String::new();
+    if options.dump_tokens {
+        text.push_str(&engine.dump_tokens(source, file)?);
+    }
+    if let Some(format) = options.dump_ast {
+        text.push_str(&engine.dump_ast(source, file, format)?);
+    }
+    return Ok(text);
+}
+
+// Writes `text` to stdout, which may be a pipe that has already been closed.
+fn write_stdout(text: &str) -> u8 {
+    match stdout().lock().write_all(text.as_bytes()) {
+        Ok(()) => EX_OK,
+        Err(_) => EX_IOERR,
     }
 }
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::ErrorMessage,
+    value::{Value, WideInt},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MapKey {
+    String(String),
+    Int(i64),
+    BigInt(WideInt),
+    Bool(bool),
+}
+
+impl MapKey {
+    pub fn from_value(literal: &Value) -> Result<MapKey, ErrorMessage> {
+        match literal {
+            Value::String(val) => Ok(MapKey::String(val.clone())),
+            Value::Int(val) => Ok(MapKey::Int(*val)),
+            Value::BigInt(val) => Ok(MapKey::BigInt(val.clone())),
+            Value::Bool(val) => Ok(MapKey::Bool(*val)),
+            Value::Float(_) => Err(ErrorMessage::new(
+                "R018",
+                "Floats can't be used as map keys, convert the key to an integer or string",
+            )),
+            _ => Err(ErrorMessage::new(
+                "R018",
+                &format!(
+                    "Unhashable map key of type {}, keys must be strings, integers or booleans",
+                    literal.type_name()
+                ),
+            )),
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        match self {
+            MapKey::String(val) => Value::String(val.clone()),
+            MapKey::Int(val) => Value::Int(*val),
+            MapKey::BigInt(val) => Value::BigInt(val.clone()),
+            MapKey::Bool(val) => Value::Bool(*val),
+        }
+    }
+}
+
+// Entries are kept in insertion order so printing and iteration are
+// deterministic; `index` maps each key to its slot in `entries`.
+#[derive(Debug, Clone, Default)]
+pub struct Map {
+    entries: Vec<(MapKey, Value)>,
+    index: HashMap<MapKey, usize>,
+}
+
+impl Map {
+    pub fn new() -> Self {
+        Map::default()
+    }
+
+    pub fn get(&self, key: &MapKey) -> Option<&Value> {
+        self.index.get(key).map(|&slot| &self.entries[slot].1)
+    }
+
+    pub fn insert(&mut self, key: MapKey, value: Value) {
+        match self.index.get(&key) {
+            Some(&slot) => self.entries[slot].1 = value,
+            None => {
+                self.index.insert(key.clone(), self.entries.len());
+                self.entries.push((key, value));
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &MapKey) -> Option<Value> {
+        let slot = self.index.remove(key)?;
+        let (_, value) = self.entries.remove(slot);
+        for (_, later) in self.index.iter_mut() {
+            if *later > slot {
+                *later -= 1;
+            }
+        }
+        Some(value)
+    }
+
+    pub fn contains_key(&self, key: &MapKey) -> bool {
+        self.index.contains_key(key)
+    }
+
+    pub fn keys(&self) -> Vec<MapKey> {
+        self.entries.iter().map(|(key, _)| key.clone()).collect()
+    }
+
+    pub fn entries(&self) -> &[(MapKey, Value)] {
+        &self.entries
+    }
+}
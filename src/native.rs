@@ -0,0 +1,283 @@
+use std::fmt;
+
+use crate::{
+    bigint::BigInt, callable::Callable, error::Error, interpreter::Interpreter, token::Token,
+    value::Value,
+};
+
+// Converts a script value into an argument of a host function. On a type
+// mismatch the error describes what was passed instead, e.g. "string".
+pub trait FromValue: Sized {
+    fn from_value(value: Value) -> Result<Self, String>;
+    // How the expected type is named in error messages, e.g. "int".
+    fn expected() -> String;
+}
+
+// Converts the result of a host function into a script value.
+pub trait IntoValue {
+    fn into_value(self) -> Value;
+}
+
+// What a host function may return: a value, or a `Result` whose error
+// becomes a runtime error at the call site.
+pub trait IntoResult {
+    fn into_result(self) -> Result<Value, String>;
+}
+
+impl<T: IntoValue> IntoResult for T {
+    fn into_result(self) -> Result<Value, String> {
+        Ok(self.into_value())
+    }
+}
+
+impl<T: IntoValue, E: fmt::Display> IntoResult for Result<T, E> {
+    fn into_result(self) -> Result<Value, String> {
+        self.map(IntoValue::into_value)
+            .map_err(|err| err.to_string())
+    }
+}
+
+// A Rust closure callable from scripts; `Args` is the tuple of its
+// parameter types, which fixes its arity.
+pub trait HostFn<Args> {
+    fn arity(&self) -> usize;
+    fn call(&self, name: &str, arguments: Vec<Value>) -> Result<Value, String>;
+}
+
+macro_rules! impl_host_fn {
+    ($($arg:ident),*) => {
+        #[allow(non_snake_case, unused_mut, unused_variables)]
+        impl<F, R, $($arg),*> HostFn<($($arg,)*)> for F
+        where
+            F: Fn($($arg),*) -> R,
+            R: IntoResult,
+            $($arg: FromValue),*
+        {
+            fn arity(&self) -> usize {
+                <[&str]>::len(&[$(stringify!($arg)),*])
+            }
+
+            fn call(&self, name: &str, arguments: Vec<Value>) -> Result<Value, String> {
+                let mut arguments = arguments.into_iter().enumerate();
+                $(
+                    let (index, value) = arguments.next().unwrap();
+                    let $arg = $arg::from_value(value).map_err(|found| {
+                        format!(
+                            "Argument {} of '{}' must be {}, not {}",
+                            index + 1,
+                            name,
+                            $arg::expected(),
+                            found
+                        )
+                    })?;
+                )*
+                (self)($($arg),*).into_result()
+            }
+        }
+    };
+}
+
+impl_host_fn!();
+impl_host_fn!(A);
+impl_host_fn!(A, B);
+impl_host_fn!(A, B, C);
+impl_host_fn!(A, B, C, D);
+impl_host_fn!(A, B, C, D, E);
+impl_host_fn!(A, B, C, D, E, G);
+
+// A host function with its argument types erased.
+type ErasedFn = dyn Fn(&str, Vec<Value>) -> Result<Value, String>;
+
+pub struct NativeFunction {
+    name: String,
+    arity: usize,
+    function: Box<ErasedFn>,
+}
+
+impl NativeFunction {
+    pub fn new<Args, F: HostFn<Args> + 'static>(name: &str, function: F) -> Self {
+        NativeFunction {
+            name: name.to_string(),
+            arity: function.arity(),
+            function: Box::new(move |name, arguments| function.call(name, arguments)),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Callable for NativeFunction {
+    fn arity(&self) -> usize {
+        self.arity
+    }
+
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        arguments: Vec<Value>,
+        paren: &Token,
+    ) -> Result<Value, Error> {
+        (self.function)(&self.name, arguments)
+            .map_err(|message| Error::new("R014", paren.span.clone(), message, "".to_string()))
+    }
+}
+
+impl fmt::Debug for NativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<native fn {}>", self.name)
+    }
+}
+
+macro_rules! impl_convert {
+    ($type:ty, $variant:ident, $expected:expr) => {
+        impl FromValue for $type {
+            fn from_value(value: Value) -> Result<Self, String> {
+                match value {
+                    Value::$variant(val) => Ok(val),
+                    other => Err(other.type_name().to_string()),
+                }
+            }
+            fn expected() -> String {
+                $expected.to_string()
+            }
+        }
+
+        impl IntoValue for $type {
+            fn into_value(self) -> Value {
+                Value::$variant(self)
+            }
+        }
+    };
+}
+
+impl_convert!(bool, Bool, "bool");
+impl_convert!(String, String, "string");
+
+// Ints too large for an `i64` are refused rather than truncated.
+impl FromValue for i64 {
+    fn from_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Int(val) => Ok(val),
+            Value::BigInt(_) => Err("an int beyond 64 bits".to_string()),
+            other => Err(other.type_name().to_string()),
+        }
+    }
+    fn expected() -> String {
+        "int".to_string()
+    }
+}
+
+impl IntoValue for i64 {
+    fn into_value(self) -> Value {
+        Value::Int(self)
+    }
+}
+
+impl FromValue for BigInt {
+    fn from_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Int(val) => Ok(BigInt::from(val)),
+            Value::BigInt(val) => Ok(val.into_bigint()),
+            other => Err(other.type_name().to_string()),
+        }
+    }
+    fn expected() -> String {
+        "int".to_string()
+    }
+}
+
+// Unlike other results, an int can fail to convert: it may exceed
+// `MAX_BITS`.
+impl IntoResult for BigInt {
+    fn into_result(self) -> Result<Value, String> {
+        Value::from_bigint(self).map_err(|err| err.message)
+    }
+}
+
+impl<E: fmt::Display> IntoResult for Result<BigInt, E> {
+    fn into_result(self) -> Result<Value, String> {
+        self.map_err(|err| err.to_string())?.into_result()
+    }
+}
+
+// Ints are accepted where a float is expected, as in arithmetic.
+impl FromValue for f64 {
+    fn from_value(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Float(val) => Ok(val),
+            Value::Int(val) => Ok(val as f64),
+            Value::BigInt(val) => Ok(val.to_f64()),
+            other => Err(other.type_name().to_string()),
+        }
+    }
+    fn expected() -> String {
+        "number".to_string()
+    }
+}
+
+impl IntoValue for f64 {
+    fn into_value(self) -> Value {
+        Value::Float(self)
+    }
+}
+
+impl FromValue for Value {
+    fn from_value(value: Value) -> Result<Self, String> {
+        Ok(value)
+    }
+    fn expected() -> String {
+        "any value".to_string()
+    }
+}
+
+impl IntoValue for Value {
+    fn into_value(self) -> Value {
+        self
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: Value) -> Result<Self, String> {
+        let Value::List(items) = value else {
+            return Err(value.type_name().to_string());
+        };
+        let items = items.borrow().clone();
+        items
+            .into_iter()
+            .map(T::from_value)
+            .collect::<Result<Vec<T>, String>>()
+            .map_err(|found| format!("list of {}", found))
+    }
+    fn expected() -> String {
+        format!("list of {}", T::expected())
+    }
+}
+
+impl<T: IntoValue> IntoValue for Vec<T> {
+    fn into_value(self) -> Value {
+        Value::new_list(self.into_iter().map(IntoValue::into_value).collect())
+    }
+}
+
+impl IntoValue for &str {
+    fn into_value(self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl IntoValue for () {
+    fn into_value(self) -> Value {
+        Value::None
+    }
+}
+
+impl<T: IntoValue> IntoValue for Option<T> {
+    fn into_value(self) -> Value {
+        match self {
+            Some(value) => value.into_value(),
+            None => Value::None,
+        }
+    }
+}
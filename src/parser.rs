@@ -1,12 +1,21 @@
-use std::error::Error;
+use std::{error::Error, rc::Rc};
 
 use crate::{
-    error::ErrorManager, expr::Expr, literal::Literal, token::Token, token_type::TokenType,
+    error::{self, ErrorManager},
+    expr::{Expr, ExprKind},
+    span::Span,
+    stmt::{FunctionDecl, Stmt},
+    token::Token,
+    token_type::TokenType,
+    value::Value,
 };
 
 pub struct Parser<'a> {
     tokens: Vec<Token>,
     current: usize,
+    function_depth: usize,
+    block_depth: usize,
+    lex_failed: bool,
     error_manager: &'a mut ErrorManager,
 }
 
@@ -14,14 +23,274 @@ impl<'a> Parser<'a> {
     pub fn new(tokens: Vec<Token>, error_manager: &'a mut ErrorManager) -> Self {
         Parser {
             current: 0,
+            function_depth: 0,
+            block_depth: 0,
+            lex_failed: error_manager.has_errors(),
             tokens,
             error_manager,
         }
     }
 
-    pub fn parse(&mut self) -> Option<Box<Expr>> {
-        let result = self.expression();
-        result.ok()
+    pub fn parse(&mut self) -> Vec<Stmt> {
+        let mut statements = vec![];
+        while !self.is_at_end() {
+            match self.declaration() {
+                Ok(stmt) => statements.push(stmt),
+                Err(_) => self.synchronize(),
+            }
+        }
+        return statements;
+    }
+
+    // Parses the tokens as one expression, for the REPL.
+    pub fn parse_expression(&mut self) -> Option<Box<Expr>> {
+        let expr = self.expression().ok()?;
+        if !self.is_at_end() {
+            self.advance();
+            self.report_error("P008", "Expected end of expression");
+            return None;
+        }
+        return Some(expr);
+    }
+
+    fn declaration(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        if self.does_match(vec![TokenType::VAR]) {
+            return self.var_declaration();
+        } else if self.does_match(vec![TokenType::FUN]) {
+            return Ok(Stmt::Function(self.function("function")?));
+        } else if self.does_match(vec![TokenType::CLASS]) {
+            return self.class_declaration();
+        }
+        return self.statement();
+    }
+
+    fn class_declaration(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        let name = self.consume_identifier("Expected class name")?;
+        let mut superclass = None;
+        if self.does_match(vec![TokenType::LESS]) {
+            let superclass_name = self.consume_identifier("Expected superclass name")?;
+            if superclass_name.lexeme == name.lexeme {
+                self.report_error("P007", "A class can't inherit from itself");
+            }
+            let span = superclass_name.span.clone();
+            superclass = Some(Expr::boxed(ExprKind::Variable(superclass_name), span));
+        }
+        self.consume(TokenType::LEFTBRACE, "Expected '{' before class body")?;
+        let mut methods = vec![];
+        while !self.check(TokenType::RIGHTBRACE) && !self.is_at_end() {
+            methods.push(self.function("method")?);
+        }
+        self.consume(TokenType::RIGHTBRACE, "Expected '}' after class body")?;
+        return Ok(Stmt::Class(name, superclass, methods));
+    }
+
+    fn function(&mut self, kind: &str) -> Result<Rc<FunctionDecl>, Box<dyn Error>> {
+        let name = self.consume_identifier(&format!("Expected {} name", kind))?;
+        self.consume(
+            TokenType::LEFTPAREN,
+            &format!("Expected '(' after {} name", kind),
+        )?;
+        let mut params = vec![];
+        if !self.check(TokenType::RIGHTPAREN) {
+            loop {
+                params.push(self.consume_identifier("Expected parameter name")?);
+                if !self.does_match(vec![TokenType::COMMA]) {
+                    break;
+                }
+            }
+        }
+        self.consume(TokenType::RIGHTPAREN, "Expected ')' after parameters")?;
+        self.consume(
+            TokenType::LEFTBRACE,
+            &format!("Expected '{{' before {} body", kind),
+        )?;
+        self.function_depth += 1;
+        let body = self.block_statement();
+        self.function_depth -= 1;
+        return Ok(Rc::new(FunctionDecl {
+            name,
+            params,
+            body: body?,
+        }));
+    }
+
+    fn var_declaration(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        let name = self.consume_identifier("Expected variable name")?;
+        let mut initializer = None;
+        if self.does_match(vec![TokenType::EQUAL]) {
+            initializer = Some(self.expression()?);
+        }
+        self.consume(
+            TokenType::SEMICOLON,
+            "Expected ';' after variable declaration",
+        )?;
+        return Ok(Stmt::Var(name, initializer));
+    }
+
+    fn statement(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        if self.does_match(vec![TokenType::PRINT]) {
+            return self.print_statement();
+        } else if self.does_match(vec![TokenType::IF]) {
+            return self.if_statement();
+        } else if self.does_match(vec![TokenType::WHILE]) {
+            return self.while_statement();
+        } else if self.does_match(vec![TokenType::FOR]) {
+            return self.for_statement();
+        } else if self.does_match(vec![TokenType::RETURN]) {
+            return self.return_statement();
+        } else if self.does_match(vec![TokenType::DEL]) {
+            return self.delete_statement();
+        } else if self.check(TokenType::LEFTBRACE) && !self.starts_map_literal() {
+            self.advance();
+            return Ok(Stmt::Block(self.block_statement()?));
+        }
+        return self.expression_statement();
+    }
+
+    // A statement starting with '{' is a block unless it opens with `key:`,
+    // in which case it is a map literal used as an expression statement.
+    fn starts_map_literal(&self) -> bool {
+        let key = self.tokens.get(self.current + 1);
+        let colon = self.tokens.get(self.current + 2);
+        match (key, colon) {
+            (Some(key), Some(colon)) => {
+                matches!(
+                    key.token_type,
+                    TokenType::STRING
+                        | TokenType::INTEGER
+                        | TokenType::TRUE
+                        | TokenType::FALSE
+                        | TokenType::IDENTIFIER
+                ) && colon.token_type == TokenType::COLON
+            }
+            _ => false,
+        }
+    }
+
+    fn delete_statement(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        let target = self.expression()?;
+        let Expr { kind, .. } = *target;
+        let ExprKind::Index(object, bracket, key) = kind else {
+            self.report_error("P005", "Can only delete indexed elements");
+            return Err("Can only delete indexed elements".into());
+        };
+        self.consume(TokenType::SEMICOLON, "Expected ';' after delete target")?;
+        return Ok(Stmt::Delete(object, bracket, key));
+    }
+
+    fn block_statement(&mut self) -> Result<Vec<Stmt>, Box<dyn Error>> {
+        let brace = self.previous().span.clone();
+        let mut statements = vec![];
+        self.block_depth += 1;
+        while !self.check(TokenType::RIGHTBRACE) && !self.is_at_end() {
+            match self.declaration() {
+                Ok(stmt) => statements.push(stmt),
+                Err(_) => self.synchronize(),
+            }
+        }
+        self.block_depth -= 1;
+        self.consume_closing(TokenType::RIGHTBRACE, "Expected '}' after block", &brace)?;
+        return Ok(statements);
+    }
+
+    fn if_statement(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        self.consume(TokenType::LEFTPAREN, "Expected '(' after 'if'")?;
+        let condition = self.expression()?;
+        self.consume(TokenType::RIGHTPAREN, "Expected ')' after if condition")?;
+        let then_branch = Box::new(self.statement()?);
+        let mut else_branch = None;
+        if self.does_match(vec![TokenType::ELSE]) {
+            else_branch = Some(Box::new(self.statement()?));
+        }
+        return Ok(Stmt::If(condition, then_branch, else_branch));
+    }
+
+    fn while_statement(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        self.consume(TokenType::LEFTPAREN, "Expected '(' after 'while'")?;
+        let condition = self.expression()?;
+        self.consume(TokenType::RIGHTPAREN, "Expected ')' after condition")?;
+        let body = Box::new(self.statement()?);
+        return Ok(Stmt::While(condition, body));
+    }
+
+    // `for (init; cond; step) body` is desugared into
+    // `{ init; while (cond) { body; step; } }`.
+    fn for_statement(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        self.consume(TokenType::LEFTPAREN, "Expected '(' after 'for'")?;
+        if self.check(TokenType::IDENTIFIER)
+            && self.tokens[self.current + 1].token_type == TokenType::IN
+        {
+            return self.for_in_statement();
+        }
+        let initializer = if self.does_match(vec![TokenType::SEMICOLON]) {
+            None
+        } else if self.does_match(vec![TokenType::VAR]) {
+            Some(self.var_declaration()?)
+        } else {
+            Some(self.expression_statement()?)
+        };
+
+        let condition = if self.check(TokenType::SEMICOLON) {
+            Expr::boxed(
+                ExprKind::Literal(Value::Bool(true)),
+                self.peek().span.clone(),
+            )
+        } else {
+            self.expression()?
+        };
+        self.consume(TokenType::SEMICOLON, "Expected ';' after loop condition")?;
+
+        let increment = if self.check(TokenType::RIGHTPAREN) {
+            None
+        } else {
+            Some(self.expression()?)
+        };
+        self.consume(TokenType::RIGHTPAREN, "Expected ')' after for clauses")?;
+
+        let mut body = self.statement()?;
+        if let Some(increment) = increment {
+            body = Stmt::Block(vec![body, Stmt::Expression(increment)]);
+        }
+        body = Stmt::While(condition, Box::new(body));
+        if let Some(initializer) = initializer {
+            body = Stmt::Block(vec![initializer, body]);
+        }
+        return Ok(body);
+    }
+
+    fn return_statement(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        if self.function_depth == 0 {
+            self.report_error("P006", "Can't return from top-level code");
+        }
+        let mut value = None;
+        if !self.check(TokenType::SEMICOLON) {
+            value = Some(self.expression()?);
+        }
+        self.consume(TokenType::SEMICOLON, "Expected ';' after return value")?;
+        return Ok(Stmt::Return(value));
+    }
+
+    // `for (name in iterable) body` walks list items, map keys in insertion
+    // order or string characters.
+    fn for_in_statement(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        let name = self.advance().clone();
+        self.advance();
+        let iterable = self.expression()?;
+        self.consume(TokenType::RIGHTPAREN, "Expected ')' after for-in clause")?;
+        let body = Box::new(self.statement()?);
+        return Ok(Stmt::ForIn(name, iterable, body));
+    }
+
+    fn print_statement(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        let value = self.expression()?;
+        self.consume(TokenType::SEMICOLON, "Expected ';' after value")?;
+        return Ok(Stmt::Print(value));
+    }
+
+    fn expression_statement(&mut self) -> Result<Stmt, Box<dyn Error>> {
+        let expr = self.expression()?;
+        self.consume(TokenType::SEMICOLON, "Expected ';' after expression")?;
+        return Ok(Stmt::Expression(expr));
     }
 
     fn expression(&mut self) -> Result<Box<Expr>, Box<dyn Error>> {
@@ -30,22 +299,71 @@ impl<'a> Parser<'a> {
     }
 
     fn block(&mut self) -> Result<Box<Expr>, Box<dyn Error>> {
-        let mut expr = self.ternary()?;
+        let mut expr = self.assignment()?;
         while self.does_match(vec![TokenType::COMMA]) {
             let operator = self.previous().clone();
-            let right = self.ternary()?;
-            expr = Box::new(Expr::Binary(expr, operator, right));
+            let right = self.assignment()?;
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Binary(expr, operator, right), span);
         }
         Ok(expr)
     }
 
+    fn assignment(&mut self) -> Result<Box<Expr>, Box<dyn Error>> {
+        let expr = self.ternary()?;
+        if self.does_match(vec![TokenType::EQUAL]) {
+            let value = self.assignment()?;
+            let span = expr.span.to(&value.span);
+            let Expr {
+                kind,
+                span: target_span,
+            } = *expr;
+            let kind = match kind {
+                ExprKind::Variable(name) => ExprKind::Assign(name, value),
+                ExprKind::Get(object, name) => ExprKind::Set(object, name, value),
+                ExprKind::Index(object, bracket, index) => {
+                    ExprKind::SetIndex(object, bracket, index, value)
+                }
+                kind => {
+                    self.report_error("P004", "Invalid assignment target");
+                    return Ok(Expr::boxed(kind, target_span));
+                }
+            };
+            return Ok(Expr::boxed(kind, span));
+        }
+        return Ok(expr);
+    }
+
     fn ternary(&mut self) -> Result<Box<Expr>, Box<dyn Error>> {
-        let mut expr = self.equality()?;
+        let mut expr = self.or()?;
         if self.does_match(vec![TokenType::QUESTION]) {
             let expr_then = self.ternary()?;
-            self.consume(TokenType::COLON, "Expected ':' in ternay operation");
+            self.consume(TokenType::COLON, "Expected ':' in ternay operation")?;
             let expr_else = self.ternary()?;
-            expr = Box::new(Expr::Ternary(expr, expr_then, expr_else));
+            let span = expr.span.to(&expr_else.span);
+            expr = Expr::boxed(ExprKind::Ternary(expr, expr_then, expr_else), span);
+        }
+        return Ok(expr);
+    }
+
+    fn or(&mut self) -> Result<Box<Expr>, Box<dyn Error>> {
+        let mut expr = self.and()?;
+        while self.does_match(vec![TokenType::OR]) {
+            let operator = self.previous().clone();
+            let right = self.and()?;
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Logical(expr, operator, right), span);
+        }
+        return Ok(expr);
+    }
+
+    fn and(&mut self) -> Result<Box<Expr>, Box<dyn Error>> {
+        let mut expr = self.equality()?;
+        while self.does_match(vec![TokenType::AND]) {
+            let operator = self.previous().clone();
+            let right = self.equality()?;
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Logical(expr, operator, right), span);
         }
         return Ok(expr);
     }
@@ -55,7 +373,8 @@ impl<'a> Parser<'a> {
         while self.does_match(vec![TokenType::BANGEQUAL, TokenType::EQUALEQUAL]) {
             let operator = self.previous().clone();
             let right = self.comparision()?;
-            expr = Box::new(Expr::Binary(expr, operator, right));
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Binary(expr, operator, right), span);
         }
         return Ok(expr);
     }
@@ -67,10 +386,12 @@ impl<'a> Parser<'a> {
             TokenType::GREATEREQUAL,
             TokenType::LESS,
             TokenType::LESSEQUAL,
+            TokenType::IN,
         ]) {
             let operator = self.previous().clone();
             let right = self.logic()?;
-            expr = Box::new(Expr::Binary(expr, operator, right));
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Binary(expr, operator, right), span);
         }
         return Ok(expr);
     }
@@ -80,7 +401,8 @@ impl<'a> Parser<'a> {
         while self.does_match(vec![TokenType::AMPERSAND, TokenType::PIPE, TokenType::XOR]) {
             let operator = self.previous().clone();
             let right = self.shift()?;
-            expr = Box::new(Expr::Binary(expr, operator, right));
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Binary(expr, operator, right), span);
         }
         return Ok(expr);
     }
@@ -90,7 +412,8 @@ impl<'a> Parser<'a> {
         while self.does_match(vec![TokenType::LESSLESS, TokenType::GREATERGREATER]) {
             let operator = self.previous().clone();
             let right = self.term()?;
-            expr = Box::new(Expr::Binary(expr, operator, right));
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Binary(expr, operator, right), span);
         }
         return Ok(expr);
     }
@@ -100,7 +423,8 @@ impl<'a> Parser<'a> {
         while self.does_match(vec![TokenType::PLUS, TokenType::MINUS]) {
             let operator = self.previous().clone();
             let right = self.factor()?;
-            expr = Box::new(Expr::Binary(expr, operator, right));
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Binary(expr, operator, right), span);
         }
         return Ok(expr);
     }
@@ -110,7 +434,8 @@ impl<'a> Parser<'a> {
         while self.does_match(vec![TokenType::SLASH, TokenType::STAR]) {
             let operator = self.previous().clone();
             let right = self.modulo()?;
-            expr = Box::new(Expr::Binary(expr, operator, right));
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Binary(expr, operator, right), span);
         }
         return Ok(expr);
     }
@@ -120,7 +445,8 @@ impl<'a> Parser<'a> {
         while self.does_match(vec![TokenType::MODULO]) {
             let operator = self.previous().clone();
             let right = self.unary()?;
-            expr = Box::new(Expr::Binary(expr, operator, right));
+            let span = expr.span.to(&right.span);
+            expr = Expr::boxed(ExprKind::Binary(expr, operator, right), span);
         }
         return Ok(expr);
     }
@@ -129,60 +455,291 @@ impl<'a> Parser<'a> {
         if self.does_match(vec![TokenType::BANG, TokenType::MINUS]) {
             let operator = self.previous().clone();
             let right = self.unary()?;
-            return Ok(Box::new(Expr::Unary(operator, right)));
+            let span = operator.span.to(&right.span);
+            return Ok(Expr::boxed(ExprKind::Unary(operator, right), span));
+        }
+        return self.call();
+    }
+
+    fn call(&mut self) -> Result<Box<Expr>, Box<dyn Error>> {
+        let mut expr = self.primary()?;
+        loop {
+            if self.does_match(vec![TokenType::LEFTPAREN]) {
+                expr = self.finish_call(expr)?;
+            } else if self.does_match(vec![TokenType::DOT]) {
+                let name = self.consume_identifier("Expected property name after '.'")?;
+                let span = expr.span.to(&name.span);
+                expr = Expr::boxed(ExprKind::Get(expr, name), span);
+            } else if self.does_match(vec![TokenType::LEFTBRACKET]) {
+                expr = self.finish_index(expr)?;
+            } else {
+                break;
+            }
+        }
+        return Ok(expr);
+    }
+
+    fn finish_call(&mut self, callee: Box<Expr>) -> Result<Box<Expr>, Box<dyn Error>> {
+        let opener = self.previous().span.clone();
+        let mut arguments = vec![];
+        if !self.check(TokenType::RIGHTPAREN) {
+            loop {
+                arguments.push(*self.assignment()?);
+                if !self.does_match(vec![TokenType::COMMA]) {
+                    break;
+                }
+            }
+        }
+        self.consume_closing(
+            TokenType::RIGHTPAREN,
+            "Expected ')' after arguments",
+            &opener,
+        )?;
+        let paren = self.previous().clone();
+        let span = self.span_from(&callee.span);
+        return Ok(Expr::boxed(ExprKind::Call(callee, paren, arguments), span));
+    }
+
+    fn finish_index(&mut self, object: Box<Expr>) -> Result<Box<Expr>, Box<dyn Error>> {
+        let bracket = self.previous().clone();
+        let mut start = None;
+        if !self.check(TokenType::COLON) {
+            start = Some(self.assignment()?);
+        }
+        if self.does_match(vec![TokenType::COLON]) {
+            let mut end = None;
+            if !self.check(TokenType::RIGHTBRACKET) {
+                end = Some(self.assignment()?);
+            }
+            self.consume_closing(
+                TokenType::RIGHTBRACKET,
+                "Expected ']' after slice",
+                &bracket.span,
+            )?;
+            let span = self.span_from(&object.span);
+            return Ok(Expr::boxed(
+                ExprKind::Slice(object, bracket, start, end),
+                span,
+            ));
+        }
+        self.consume_closing(
+            TokenType::RIGHTBRACKET,
+            "Expected ']' after index",
+            &bracket.span,
+        )?;
+        let span = self.span_from(&object.span);
+        match start {
+            Some(index) => Ok(Expr::boxed(ExprKind::Index(object, bracket, index), span)),
+            None => {
+                self.report_error("P003", "Expected an index");
+                Err("Expected an index".into())
+            }
         }
-        return self.primary();
     }
 
     fn primary(&mut self) -> Result<Box<Expr>, Box<dyn Error>> {
+        let start = self.peek().span.clone();
         if self.does_match(vec![TokenType::TRUE]) {
-            return Ok(Box::new(Expr::Literal(Literal::Bool(true))));
+            return Ok(Expr::boxed(ExprKind::Literal(Value::Bool(true)), start));
         } else if self.does_match(vec![TokenType::FALSE]) {
-            return Ok(Box::new(Expr::Literal(Literal::Bool(false))));
+            return Ok(Expr::boxed(ExprKind::Literal(Value::Bool(false)), start));
         } else if self.does_match(vec![TokenType::NIL]) {
-            return Ok(Box::new(Expr::Literal(Literal::None)));
+            return Ok(Expr::boxed(ExprKind::Literal(Value::None), start));
         } else if self.does_match(vec![
             TokenType::STRING,
             TokenType::INTEGER,
             TokenType::FLOAT,
         ]) {
-            return Ok(Box::new(Expr::Literal(
-                self.previous().literal.clone().unwrap(),
-            )));
+            return Ok(Expr::boxed(
+                ExprKind::Literal(self.previous().literal.clone().unwrap()),
+                start,
+            ));
+        } else if self.does_match(vec![TokenType::INTERPOLATION]) {
+            return self.interpolation();
+        } else if self.does_match(vec![TokenType::IDENTIFIER]) {
+            return Ok(Expr::boxed(
+                ExprKind::Variable(self.previous().clone()),
+                start,
+            ));
+        } else if self.does_match(vec![TokenType::THIS]) {
+            return Ok(Expr::boxed(ExprKind::This(self.previous().clone()), start));
+        } else if self.does_match(vec![TokenType::SUPER]) {
+            let keyword = self.previous().clone();
+            self.consume(TokenType::DOT, "Expected '.' after 'super'")?;
+            let method = self.consume_identifier("Expected superclass method name")?;
+            let span = self.span_from(&start);
+            return Ok(Expr::boxed(ExprKind::Super(keyword, method), span));
+        } else if self.does_match(vec![TokenType::LEFTBRACKET]) {
+            let mut items = vec![];
+            if !self.check(TokenType::RIGHTBRACKET) {
+                loop {
+                    items.push(*self.assignment()?);
+                    if !self.does_match(vec![TokenType::COMMA])
+                        || self.check(TokenType::RIGHTBRACKET)
+                    {
+                        break;
+                    }
+                }
+            }
+            self.consume_closing(
+                TokenType::RIGHTBRACKET,
+                "Expected ']' after list items",
+                &start,
+            )?;
+            let span = self.span_from(&start);
+            return Ok(Expr::boxed(ExprKind::List(items), span));
+        } else if self.does_match(vec![TokenType::LEFTBRACE]) {
+            let brace = self.previous().clone();
+            let mut entries = vec![];
+            if !self.check(TokenType::RIGHTBRACE) {
+                loop {
+                    let key = *self.assignment()?;
+                    self.consume(TokenType::COLON, "Expected ':' after map key")?;
+                    let value = *self.assignment()?;
+                    entries.push((key, value));
+                    if !self.does_match(vec![TokenType::COMMA]) || self.check(TokenType::RIGHTBRACE)
+                    {
+                        break;
+                    }
+                }
+            }
+            self.consume_closing(
+                TokenType::RIGHTBRACE,
+                "Expected '}' after map entries",
+                &start,
+            )?;
+            let span = self.span_from(&start);
+            return Ok(Expr::boxed(ExprKind::Map(brace, entries), span));
         } else if self.does_match(vec![TokenType::LEFTPAREN]) {
             let expr = self.expression()?;
-            self.consume(TokenType::RIGHTPAREN, "Expected ')' after expression");
-            return Ok(Box::new(Expr::Grouping(expr)));
+            self.consume_closing(
+                TokenType::RIGHTPAREN,
+                "Expected ')' after expression",
+                &start,
+            )?;
+            let span = self.span_from(&start);
+            return Ok(Expr::boxed(ExprKind::Grouping(expr), span));
         }
-        self.report_error("Expected an expression");
+        self.report_error("P003", "Expected an expression");
         return Err("Expected an expression".into());
     }
 
-    fn consume(&mut self, token_type: TokenType, message: &str) {
+    // The lexer splits `"a ${x} b"` into INTERPOLATION("a "), the tokens of
+    // `x`, then INTERPOLATIONEND(" b"); pieces alternate with embedded
+    // expressions.
+    fn interpolation(&mut self) -> Result<Box<Expr>, Box<dyn Error>> {
+        let start = self.previous().span.clone();
+        let mut parts = vec![*self.string_piece()];
+        loop {
+            // A piece resumed by the closing '}' right away means `${}` was
+            // empty; a piece opening with '"' is a nested string instead.
+            let piece = matches!(
+                self.peek().token_type,
+                TokenType::INTERPOLATION | TokenType::INTERPOLATIONEND
+            );
+            if piece && self.peek().lexeme.starts_with('}') {
+                self.report_error("P003", "Expected an expression inside '${}'");
+                return Err("Expected an expression inside '${}'".into());
+            }
+            parts.push(*self.expression()?);
+            if self.does_match(vec![TokenType::INTERPOLATION]) {
+                parts.push(*self.string_piece());
+            } else if self.does_match(vec![TokenType::INTERPOLATIONEND]) {
+                parts.push(*self.string_piece());
+                let span = self.span_from(&start);
+                return Ok(Expr::boxed(ExprKind::Interpolation(parts), span));
+            } else {
+                self.report_error("P001", "Expected '}' after interpolated expression");
+                return Err("Expected '}' after interpolated expression".into());
+            }
+        }
+    }
+
+    fn string_piece(&self) -> Box<Expr> {
+        let token = self.previous();
+        Expr::boxed(
+            ExprKind::Literal(token.literal.clone().unwrap()),
+            token.span.clone(),
+        )
+    }
+
+    // The span from `start` through the most recently consumed token.
+    fn span_from(&self, start: &Span) -> Span {
+        start.to(&self.previous().span)
+    }
+
+    fn consume(&mut self, token_type: TokenType, message: &str) -> Result<(), Box<dyn Error>> {
         if self.check(token_type) {
             self.advance();
+            return Ok(());
+        }
+        self.report_error("P001", message);
+        return Err(message.into());
+    }
+
+    fn consume_identifier(&mut self, message: &str) -> Result<Token, Box<dyn Error>> {
+        if self.check(TokenType::IDENTIFIER) {
+            return Ok(self.advance().clone());
+        }
+        self.report_error("P001", message);
+        return Err(message.into());
+    }
+
+    // Like `consume`, but a missing closer also points back at its opener.
+    fn consume_closing(
+        &mut self,
+        token_type: TokenType,
+        message: &str,
+        opener: &Span,
+    ) -> Result<(), Box<dyn Error>> {
+        if self.check(token_type) {
+            self.advance();
+            return Ok(());
+        }
+        let error = self
+            .error("P002", message)
+            .with_label(opener.clone(), "unclosed delimiter opened here");
+        self.add_error(error);
+        return Err(message.into());
+    }
+
+    fn report_error(&mut self, code: &'static str, message: &str) {
+        let error = self.error(code, message);
+        self.add_error(error);
+    }
+
+    // Running out of input after a lexer error is almost always fallout from
+    // it (e.g. an unterminated string swallowing the rest of the file), and
+    // stumbling on a character the lexer rejected certainly is, so neither is
+    // reported on top of it.
+    fn add_error(&mut self, error: error::Error) {
+        if self.is_at_end() && self.lex_failed {
             return;
         }
-        self.report_error(message);
+        if self.check(TokenType::ERROR) {
+            return;
+        }
+        self.error_manager.add_parse_error(error);
     }
 
-    fn report_error(&mut self, message: &str) {
-        let token = self.previous();
-        if token.token_type == TokenType::EOF {
-            self.error_manager
-                .add_error(token.line, message.to_string(), "at end".to_string());
+    fn error(&self, code: &'static str, message: &str) -> error::Error {
+        let token = if self.current == 0 {
+            self.peek()
         } else {
-            let why = format!("at '{}'", token.lexeme).to_string();
-            self.error_manager
-                .add_error(token.line, message.to_string(), why);
-        }
+            self.previous()
+        };
+        let why = if token.token_type == TokenType::EOF {
+            "at end".to_string()
+        } else {
+            format!("at '{}'", token.lexeme)
+        };
+        error::Error::new(code, token.span.clone(), message.to_string(), why)
     }
+    // Skips tokens after a syntax error until the start of the next statement,
+    // so that one mistake is reported once rather than as a chain of errors.
+    // Inside a block we also stop at its closing '}', leaving it for the block.
     fn synchronize(&mut self) {
-        self.advance();
-        while (!self.is_at_end()) {
-            if (self.previous().token_type == TokenType::SEMICOLON) {
-                return;
-            }
+        while !self.is_at_end() {
             match self.peek().token_type {
                 TokenType::CLASS => return,
                 TokenType::IF => return,
@@ -192,11 +749,39 @@ impl<'a> Parser<'a> {
                 TokenType::WHILE => return,
                 TokenType::PRINT => return,
                 TokenType::RETURN => return,
+                TokenType::DEL => return,
+                TokenType::RIGHTBRACE if self.block_depth > 0 => return,
+                TokenType::SEMICOLON => {
+                    self.advance();
+                    return;
+                }
+                TokenType::LEFTBRACE => {
+                    self.skip_braces();
+                    continue;
+                }
                 _ => (),
             }
             self.advance();
         }
     }
+
+    // Skips a whole `{ ... }` group, so statements inside a body we are
+    // abandoning aren't mistaken for the next statement.
+    fn skip_braces(&mut self) {
+        let mut depth = 0;
+        while !self.is_at_end() {
+            match self.advance().token_type {
+                TokenType::LEFTBRACE => depth += 1,
+                TokenType::RIGHTBRACE => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
     fn does_match(&mut self, token_list: Vec<TokenType>) -> bool {
         for token in token_list.iter() {
             if self.check(*token) {
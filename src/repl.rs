@@ -0,0 +1,141 @@
+use hiscript::{AstFormat, Engine, ErrorFormat};
+
+use crate::{
+    cli::{Options, EX_IOERR, EX_OK},
+    line_editor::{LineEditor, ReadLine},
+};
+
+const HELP: &str = "\
+:help           Show this help
+:reset          Forget everything defined so far
+:load <file>    Run a script in this session
+:ast <expr>     Show the syntax tree of an expression
+:type <expr>    Show the type of an expression's value
+:quit           Leave the prompt (as does Ctrl-D)
+
+Input with unclosed brackets or strings continues on the next line, and
+an empty line submits it as it is. The value of a bare expression is
+printed.";
+
+// An interactive session. Everything defined at the prompt lives across
+// inputs; errors are reported after each input.
+pub struct Repl {
+    engine: Engine,
+    editor: LineEditor,
+    error_format: ErrorFormat,
+    script_args: Vec<String>,
+    inputs: usize,
+}
+
+impl Repl {
+    pub fn new(engine: Engine, options: &Options) -> Self {
+        Repl {
+            engine,
+            editor: LineEditor::new(LineEditor::default_history_file()),
+            error_format: options.error_format,
+            script_args: options.script_args.clone(),
+            inputs: 0,
+        }
+    }
+
+    pub fn run(&mut self) -> u8 {
+        loop {
+            match self.read_input() {
+                Ok(Some(input)) => {
+                    if !self.command(&input) {
+                        return EX_OK;
+                    }
+                }
+                Ok(None) => return EX_OK,
+                Err(err) => {
+                    eprintln!("Could not read input: {}", err);
+                    return EX_IOERR;
+                }
+            }
+        }
+    }
+
+    // Reads lines until they form a complete input, or `None` at end of input.
+    fn read_input(&mut self) -> std::io::Result<Option<String>> {
+        let mut input = String::new();
+        loop {
+            let prompt = if input.is_empty() { "> " } else { "... " };
+            match self.editor.read_line(prompt)? {
+                ReadLine::Line(line) => {
+                    self.editor.add_history(&line);
+                    if line.trim().is_empty() {
+                        if input.is_empty() {
+                            continue;
+                        }
+                        return Ok(Some(input));
+                    }
+                    input += &line;
+                    input.push('\n');
+                    if input.trim_start().starts_with(':') || Engine::is_complete(&input) {
+                        return Ok(Some(input));
+                    }
+                }
+                ReadLine::Interrupted => input.clear(),
+                ReadLine::Eof => return Ok(None),
+            }
+        }
+    }
+
+    // Handles one input, returning false when the session should end.
+    fn command(&mut self, input: &str) -> bool {
+        let input = input.trim();
+        let file = self.next_file();
+        let Some(command) = input.strip_prefix(':') else {
+            match self.engine.eval_named(input, &file) {
+                Ok(value) => {
+                    if value.is_none() {
+                        return true;
+                    }
+                    if let Err(err) = value.print() {
+                        eprintln!("Could not write output: {}", err);
+                        return false;
+                    }
+                }
+                Err(diagnostics) => diagnostics.report(self.error_format),
+            }
+            return true;
+        };
+        let (name, argument) = command
+            .split_once(char::is_whitespace)
+            .unwrap_or((command, ""));
+        let argument = argument.trim();
+        match name {
+            "help" => println!("{}", HELP),
+            "quit" | "q" => return false,
+            "reset" => {
+                self.engine.reset();
+                self.engine.set_args(&self.script_args);
+            }
+            "load" => match self.engine.run_file(argument) {
+                Ok(Ok(())) => (),
+                Ok(Err(diagnostics)) => diagnostics.report(self.error_format),
+                Err(err) => eprintln!("Could not read '{}': {}", argument, err),
+            },
+            "ast" => match self
+                .engine
+                .expression_ast(argument, &file, AstFormat::Sexpr)
+            {
+                Ok(ast) => println!("{}", ast),
+                Err(diagnostics) => diagnostics.report(self.error_format),
+            },
+            "type" => match self.engine.eval_named(argument, &file) {
+                Ok(value) => println!("{}", value.type_name()),
+                Err(diagnostics) => diagnostics.report(self.error_format),
+            },
+            _ => eprintln!("Unknown command ':{}', see :help", name),
+        }
+        return true;
+    }
+
+    // Each input gets its own file name so that errors in functions defined
+    // by earlier inputs still show the right source.
+    fn next_file(&mut self) -> String {
+        self.inputs += 1;
+        format!("<repl:{}>", self.inputs)
+    }
+}
@@ -0,0 +1,33 @@
+use std::rc::Rc;
+
+// A region of source text. `start` and `end` are byte offsets into the file;
+// `line` and `column` (both one-based, column counted in characters) locate
+// `start` for humans.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub file: Rc<str>,
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    pub fn new(file: Rc<str>, start: usize, end: usize, line: usize, column: usize) -> Self {
+        Span {
+            file,
+            start,
+            end,
+            line,
+            column,
+        }
+    }
+
+    // The span covering `self` through `other`.
+    pub fn to(&self, other: &Span) -> Span {
+        Span {
+            end: other.end.max(self.end),
+            ..self.clone()
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    cell::RefCell,
+    fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    bigint::{digits_may_fit, BigInt, MAX_BITS},
+    capabilities::Capabilities,
+    interpreter::Environment,
+    native::{HostFn, NativeFunction},
+    value::{Lesser, Value},
+};
+
+// The built-in functions every engine starts with. They are ordinary
+// globals, so a script may shadow any of them. The file system and stdio
+// functions check `capabilities` on every call, so grants made later still
+// apply.
+pub fn define(globals: &mut Environment, capabilities: &Rc<RefCell<Capabilities>>) {
+    define_fn(globals, "clock", clock);
+    define_fn(globals, "len", len);
+    define_fn(globals, "str", |value: Value| value.to_string());
+    define_fn(globals, "int", int);
+    define_fn(globals, "float", float);
+    define_fn(globals, "type", |value: Value| value.type_name());
+
+    define_fn(globals, "abs", abs);
+    define_fn(globals, "min", |a: Value, b: Value| pick(a, b, true));
+    define_fn(globals, "max", |a: Value, b: Value| pick(a, b, false));
+    define_fn(globals, "floor", |x: Value| round(x, f64::floor));
+    define_fn(globals, "ceil", |x: Value| round(x, f64::ceil));
+    define_fn(globals, "sqrt", |x: f64| -> Result<f64, String> {
+        if x < 0.0 {
+            return Err("sqrt() of a negative number".to_string());
+        }
+        Ok(x.sqrt())
+    });
+    define_fn(globals, "pow", pow);
+    define_fn(globals, "sin", f64::sin);
+    define_fn(globals, "cos", f64::cos);
+    define_fn(globals, "tan", f64::tan);
+    define_fn(globals, "asin", f64::asin);
+    define_fn(globals, "acos", f64::acos);
+    define_fn(globals, "atan", f64::atan);
+    define_fn(globals, "atan2", f64::atan2);
+
+    define_fn(globals, "upper", |s: String| s.to_uppercase());
+    define_fn(globals, "lower", |s: String| s.to_lowercase());
+    define_fn(globals, "trim", |s: String| s.trim().to_string());
+    define_fn(globals, "split", split);
+    define_fn(globals, "join", join);
+    define_fn(globals, "replace", |s: String, from: String, to: String| {
+        s.replace(&from, &to)
+    });
+    define_fn(globals, "contains", |container: Value, item: Value| {
+        container.contains(&item).map_err(|err| err.message)
+    });
+    define_fn(globals, "starts_with", |s: String, prefix: String| {
+        s.starts_with(&prefix)
+    });
+    define_fn(globals, "substr", substr);
+
+    let caps = capabilities.clone();
+    define_fn(globals, "read_line", move || {
+        caps.borrow().check_stdio()?;
+        read_line()
+    });
+    let caps = capabilities.clone();
+    define_fn(globals, "eprint", move |value: Value| {
+        caps.borrow().check_stdio()?;
+        writeln!(io::stderr().lock(), "{}", value)
+            .map_err(|err| format!("Could not write to standard error: {}", err))
+    });
+    let caps = capabilities.clone();
+    define_fn(globals, "read_file", move |path: String| {
+        let resolved = caps.borrow().check_fs(&path)?;
+        fs::read_to_string(resolved).map_err(|err| io_error(&path, err))
+    });
+    let caps = capabilities.clone();
+    define_fn(globals, "write_file", move |path: String, text: String| {
+        let resolved = caps.borrow().check_fs(&path)?;
+        fs::write(resolved, text).map_err(|err| io_error(&path, err))
+    });
+    let caps = capabilities.clone();
+    define_fn(globals, "append_file", move |path: String, text: String| {
+        let resolved = caps.borrow().check_fs(&path)?;
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(resolved)
+            .and_then(|mut file| file.write_all(text.as_bytes()))
+            .map_err(|err| io_error(&path, err))
+    });
+    let caps = capabilities.clone();
+    define_fn(globals, "exists", move |path: String| {
+        let resolved = caps.borrow().check_fs(&path)?;
+        Ok::<_, String>(resolved.exists())
+    });
+    let caps = capabilities.clone();
+    define_fn(globals, "list_dir", move |path: String| {
+        let resolved = caps.borrow().check_fs(&path)?;
+        list_dir(resolved).map_err(|err| io_error(&path, err))
+    });
+}
+
+fn define_fn<Args, F: HostFn<Args> + 'static>(globals: &mut Environment, name: &str, f: F) {
+    let function = NativeFunction::new(name, f);
+    globals.define(name.to_string(), Value::Native(Rc::new(function)));
+}
+
+// Seconds since the Unix epoch, for timing scripts.
+fn clock() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
+fn len(value: Value) -> Result<i64, String> {
+    let len = match &value {
+        Value::String(s) => s.chars().count(),
+        Value::List(items) => items.borrow().len(),
+        Value::Map(map) => map.borrow().entries().len(),
+        other => return Err(format!("len() is not defined for {}", other.type_name())),
+    };
+    Ok(len as i64)
+}
+
+fn int(value: Value) -> Result<Value, String> {
+    match value {
+        Value::Int(_) | Value::BigInt(_) => Ok(value),
+        Value::Float(val) => float_to_int(val),
+        Value::Bool(val) => Ok(Value::Int(val as i64)),
+        Value::String(s) => {
+            let text = s.trim();
+            let digits = text.trim_start_matches(['+', '-']).trim_start_matches('0');
+            if !digits_may_fit(digits.len()) {
+                return Err(too_large("int()"));
+            }
+            let value =
+                BigInt::parse(text).ok_or_else(|| format!("Cannot convert {:?} to int", s))?;
+            Value::from_bigint(value).map_err(|_| too_large("int()"))
+        }
+        other => Err(format!("Cannot convert {} to int", other.type_name())),
+    }
+}
+
+fn float(value: Value) -> Result<f64, String> {
+    match value {
+        Value::Int(val) => Ok(val as f64),
+        Value::BigInt(val) => Ok(val.to_f64()),
+        Value::Float(val) => Ok(val),
+        Value::String(s) => s
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| format!("Cannot convert {:?} to float", s)),
+        other => Err(format!("Cannot convert {} to float", other.type_name())),
+    }
+}
+
+// The integer part of `val`; NaN and infinities have none.
+fn float_to_int(val: f64) -> Result<Value, String> {
+    let value = BigInt::from_f64(val).ok_or_else(|| format!("Cannot convert {} to int", val))?;
+    Value::from_bigint(value).map_err(|err| err.message)
+}
+
+fn abs(value: Value) -> Result<Value, String> {
+    match value {
+        Value::Int(val) => Value::from_bigint(BigInt::from(val).abs()).map_err(|err| err.message),
+        Value::BigInt(val) => Value::from_bigint(val.abs()).map_err(|err| err.message),
+        Value::Float(val) => Ok(Value::Float(val.abs())),
+        other => Err(format!("abs() is not defined for {}", other.type_name())),
+    }
+}
+
+// The smaller of `a` and `b` when `smaller` is set, otherwise the larger,
+// using the same ordering as `<`.
+fn pick(a: Value, b: Value, smaller: bool) -> Result<Value, String> {
+    let a_first = match a.clone().lesser(b.clone()) {
+        Ok(Value::Bool(less)) => less == smaller,
+        _ => {
+            return Err(format!(
+                "Cannot compare {} with {}",
+                a.type_name(),
+                b.type_name()
+            ))
+        }
+    };
+    Ok(if a_first { a } else { b })
+}
+
+// Rounds a number to an int with `f`; ints are returned as they are.
+fn round(value: Value, f: fn(f64) -> f64) -> Result<Value, String> {
+    match value {
+        Value::Int(_) | Value::BigInt(_) => Ok(value),
+        Value::Float(val) => float_to_int(f(val)),
+        other => Err(format!("Cannot round {}", other.type_name())),
+    }
+}
+
+// Stays an exact int for an int base and non-negative int exponent.
+fn pow(base: Value, exponent: Value) -> Result<Value, String> {
+    match (&base, &exponent) {
+        (Value::Int(b), Value::Int(e)) if *e >= 0 => int_pow(&BigInt::from(*b), *e),
+        (Value::BigInt(b), Value::Int(e)) if *e >= 0 => int_pow(b, *e),
+        (
+            Value::Int(_) | Value::BigInt(_) | Value::Float(_),
+            Value::Int(_) | Value::BigInt(_) | Value::Float(_),
+        ) => {
+            let (b, e) = (float(base)?, float(exponent)?);
+            Ok(Value::Float(b.powf(e)))
+        }
+        _ => Err(format!(
+            "pow() is not defined for {} and {}",
+            base.type_name(),
+            exponent.type_name()
+        )),
+    }
+}
+
+fn int_pow(base: &BigInt, exponent: i64) -> Result<Value, String> {
+    match base.pow(exponent as u64) {
+        Some(result) => Value::from_bigint(result).map_err(|_| too_large("pow()")),
+        None => Err(too_large("pow()")),
+    }
+}
+
+fn too_large(name: &str) -> String {
+    format!(
+        "{} result is too large, the limit is {} bits",
+        name, MAX_BITS
+    )
+}
+
+// An empty separator splits into single characters.
+fn split(s: String, separator: String) -> Vec<String> {
+    if separator.is_empty() {
+        return s.chars().map(String::from).collect();
+    }
+    s.split(&separator).map(String::from).collect()
+}
+
+// Items that aren't strings are joined as `str` would show them.
+fn join(items: Vec<Value>, separator: String) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(&separator)
+}
+
+// `length` characters of `s` from character `start`, or fewer if `s` ends
+// first.
+fn substr(s: String, start: i64, length: i64) -> Result<String, String> {
+    let count = s.chars().count();
+    if start < 0 || start as usize > count {
+        return Err(format!(
+            "substr() start {} is out of range for a string of length {}",
+            start, count
+        ));
+    }
+    if length < 0 {
+        return Err(format!("substr() length {} is negative", length));
+    }
+    Ok(s.chars()
+        .skip(start as usize)
+        .take(length as usize)
+        .collect())
+}
+
+// A line of standard input without its line ending, or null at its end.
+fn read_line() -> Result<Option<String>, String> {
+    let mut line = String::new();
+    match io::stdin().lock().read_line(&mut line) {
+        Ok(0) => Ok(None),
+        Ok(_) => {
+            let line = line.strip_suffix('\n').unwrap_or(&line);
+            Ok(Some(line.strip_suffix('\r').unwrap_or(line).to_string()))
+        }
+        Err(err) => Err(format!("Could not read standard input: {}", err)),
+    }
+}
+
+// The names of the entries in a directory, sorted.
+fn list_dir(path: PathBuf) -> io::Result<Vec<String>> {
+    let mut names = vec![];
+    for entry in fs::read_dir(path)? {
+        names.push(entry?.file_name().to_string_lossy().into_owned());
+    }
+    names.sort();
+    Ok(names)
+}
+
+fn io_error(path: &str, err: io::Error) -> String {
+    format!("Could not access '{}': {}", path, err)
+}
@@ -0,0 +1,24 @@
+use std::rc::Rc;
+
+use crate::{expr::Expr, token::Token};
+#[derive(Debug)]
+pub enum Stmt {
+    Expression(Box<Expr>),
+    Print(Box<Expr>),
+    Var(Token, Option<Box<Expr>>),
+    Block(Vec<Stmt>),
+    If(Box<Expr>, Box<Stmt>, Option<Box<Stmt>>),
+    While(Box<Expr>, Box<Stmt>),
+    ForIn(Token, Box<Expr>, Box<Stmt>),
+    Delete(Box<Expr>, Token, Box<Expr>),
+    Function(Rc<FunctionDecl>),
+    Return(Option<Box<Expr>>),
+    Class(Token, Option<Box<Expr>>, Vec<Rc<FunctionDecl>>),
+}
+
+#[derive(Debug)]
+pub struct FunctionDecl {
+    pub name: Token,
+    pub params: Vec<Token>,
+    pub body: Vec<Stmt>,
+}
@@ -1,22 +1,17 @@
-use crate::{literal::Literal, token_type::TokenType};
+use crate::{span::Span, token_type::TokenType, value::Value};
 #[derive(Debug, Clone)]
 pub struct Token {
     pub token_type: TokenType,
-    pub line: usize,
+    pub span: Span,
     pub lexeme: String,
-    pub literal: Option<Literal>,
+    pub literal: Option<Value>,
 }
 
 impl Token {
-    pub fn new(
-        token_type: TokenType,
-        line: usize,
-        lexeme: String,
-        literal: Option<Literal>,
-    ) -> Self {
+    pub fn new(token_type: TokenType, span: Span, lexeme: String, literal: Option<Value>) -> Self {
         Token {
             token_type,
-            line,
+            span,
             lexeme,
             literal,
         }
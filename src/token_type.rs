@@ -1,9 +1,12 @@
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TokenType {
     LEFTPAREN,
     RIGHTPAREN,
     LEFTBRACE,
     RIGHTBRACE,
+    LEFTBRACKET,
+    RIGHTBRACKET,
     COMMA,
     DOT,
     MINUS,
@@ -31,16 +34,20 @@ pub enum TokenType {
 
     IDENTIFIER,
     STRING,
+    INTERPOLATION,
+    INTERPOLATIONEND,
     INTEGER,
     FLOAT,
 
     AND,
     CLASS,
+    DEL,
     ELSE,
     FALSE,
     FUN,
     FOR,
     IF,
+    IN,
     NIL,
     OR,
     PRINT,
@@ -50,5 +57,8 @@ pub enum TokenType {
     TRUE,
     VAR,
     WHILE,
+    // Stands in for a character the lexer rejected, which has been reported
+    // already.
+    ERROR,
     EOF,
 }
@@ -0,0 +1,554 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    io::{self, stdout, Write},
+    ops::Deref,
+    rc::Rc,
+};
+
+use crate::{
+    bigint::{BigInt, MAX_BITS},
+    callable::Function,
+    class::{Class, Instance},
+    error::ErrorMessage,
+    map::{Map, MapKey},
+    native::NativeFunction,
+};
+
+thread_local! {
+    // The lists and maps currently being formatted or compared, by address,
+    // so that one containing itself doesn't recurse forever. Formatting
+    // visits one container at a time, comparing a pair.
+    static VISITING: RefCell<Vec<(usize, usize)>> = const { RefCell::new(Vec::new()) };
+}
+
+// Runs `body`, or gives `None` if `key` is already being visited further up.
+fn guarded<R>(key: (usize, usize), body: impl FnOnce() -> R) -> Option<R> {
+    if VISITING.with(|seen| seen.borrow().contains(&key)) {
+        return None;
+    }
+    VISITING.with(|seen| seen.borrow_mut().push(key));
+    let result = body();
+    VISITING.with(|seen| seen.borrow_mut().pop());
+    return Some(result);
+}
+
+// Formats a container with `body`, unless the container at `ptr` is already
+// being formatted further up, in which case `placeholder` is written.
+fn fmt_guarded(
+    f: &mut fmt::Formatter<'_>,
+    ptr: usize,
+    placeholder: &str,
+    body: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    match guarded((ptr, 0), || body(f)) {
+        Some(result) => result,
+        None => write!(f, "{}", placeholder),
+    }
+}
+
+// Compares two containers with `body`, unless the same pair is already being
+// compared further up. Then they are taken as equal, as nothing found so far
+// tells them apart.
+fn eq_guarded<T>(a: &Rc<RefCell<T>>, b: &Rc<RefCell<T>>, body: impl FnOnce() -> bool) -> bool {
+    if Rc::ptr_eq(a, b) {
+        return true;
+    }
+    let key = (Rc::as_ptr(a) as usize, Rc::as_ptr(b) as usize);
+    return guarded(key, body).unwrap_or(true);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WideInt(BigInt);
+
+impl WideInt {
+    pub fn into_bigint(self) -> BigInt {
+        self.0
+    }
+}
+
+impl Deref for WideInt {
+    type Target = BigInt;
+    fn deref(&self) -> &BigInt {
+        &self.0
+    }
+}
+
+impl fmt::Display for WideInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Clone)]
+pub enum Value {
+    String(String),
+    Float(f64),
+    Int(i64),
+    BigInt(WideInt),
+    Bool(bool),
+    Function(Rc<Function>),
+    Native(Rc<NativeFunction>),
+    Class(Rc<Class>),
+    Instance(Rc<RefCell<Instance>>),
+    List(Rc<RefCell<Vec<Value>>>),
+    Map(Rc<RefCell<Map>>),
+    None,
+}
+impl Value {
+    // Fails rather than panicking when stdout is closed, e.g. a pipe whose
+    // reader has gone away.
+    pub fn print(&self) -> io::Result<()> {
+        writeln!(stdout().lock(), "{}", self)
+    }
+
+    pub fn is_none(&self) -> bool {
+        matches!(self, Value::None)
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::String(_) => "string",
+            Value::Float(_) => "float",
+            Value::Int(_) | Value::BigInt(_) => "int",
+            Value::Bool(_) => "bool",
+            Value::Function(_) | Value::Native(_) => "function",
+            Value::Class(_) => "class",
+            Value::Instance(_) => "instance",
+            Value::List(_) => "list",
+            Value::Map(_) => "map",
+            Value::None => "null",
+        }
+    }
+
+    // How a value is named in error messages: scalars by their value, with
+    // strings quoted, and everything else by its type.
+    pub fn describe(&self) -> String {
+        match self {
+            Value::String(val) => format!("{:?}", val),
+            Value::Int(_) | Value::BigInt(_) | Value::Float(_) | Value::Bool(_) | Value::None => {
+                self.to_string()
+            }
+            _ => self.type_name().to_string(),
+        }
+    }
+
+    // Integer results are kept as `Int` whenever they fit. Every integer
+    // result passes through here, so this is where `MAX_BITS` is enforced.
+    pub fn from_bigint(value: BigInt) -> Result<Value, ErrorMessage> {
+        if value.bits() > MAX_BITS {
+            return Err(too_large());
+        }
+        match value.to_i64() {
+            Some(value) => Ok(Value::Int(value)),
+            None => Ok(Value::BigInt(WideInt(value))),
+        }
+    }
+
+    pub fn new_list(items: Vec<Value>) -> Value {
+        Value::List(Rc::new(RefCell::new(items)))
+    }
+
+    pub fn new_map(map: Map) -> Value {
+        Value::Map(Rc::new(RefCell::new(map)))
+    }
+
+    // Equality behind `==`, `!=` and membership tests: numbers compare across
+    // Int/Float, scalars by value, lists and maps by their contents and
+    // everything else by identity. Values of different types are simply
+    // unequal.
+    pub fn equals(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Int(a), Value::Int(b)) => a == b,
+            (Value::Float(a), Value::Float(b)) => a == b,
+            (Value::Int(a), Value::Float(b)) => (*a as f64) == *b,
+            (Value::Float(a), Value::Int(b)) => *a == (*b as f64),
+            (Value::BigInt(a), Value::BigInt(b)) => a == b,
+            (Value::BigInt(a), Value::Float(b)) => a.to_f64() == *b,
+            (Value::Float(a), Value::BigInt(b)) => *a == b.to_f64(),
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::None, Value::None) => true,
+            (Value::Function(a), Value::Function(b)) => Rc::ptr_eq(a, b),
+            (Value::Native(a), Value::Native(b)) => Rc::ptr_eq(a, b),
+            (Value::Class(a), Value::Class(b)) => Rc::ptr_eq(a, b),
+            (Value::Instance(a), Value::Instance(b)) => Rc::ptr_eq(a, b),
+            (Value::List(a), Value::List(b)) => eq_guarded(a, b, || {
+                let (a, b) = (a.borrow(), b.borrow());
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.equals(b))
+            }),
+            // Maps are equal whatever order their entries were added in.
+            (Value::Map(a), Value::Map(b)) => eq_guarded(a, b, || {
+                let (a, b) = (a.borrow(), b.borrow());
+                a.entries().len() == b.entries().len()
+                    && a.entries()
+                        .iter()
+                        .all(|(key, value)| b.get(key).is_some_and(|other| value.equals(other)))
+            }),
+            _ => false,
+        }
+    }
+
+    pub fn contains(&self, item: &Value) -> Result<bool, ErrorMessage> {
+        match (self, item) {
+            (Value::Map(map), _) => Ok(map.borrow().contains_key(&MapKey::from_value(item)?)),
+            (Value::List(items), _) => Ok(items.borrow().iter().any(|val| val.equals(item))),
+            (Value::String(haystack), Value::String(needle)) => {
+                Ok(haystack.contains(needle.as_str()))
+            }
+            (container, item) => Err(ErrorMessage::new(
+                "R002",
+                &format!(
+                    "'in' not supported between {} and {}",
+                    item.type_name(),
+                    container.type_name()
+                ),
+            )),
+        }
+    }
+
+    fn fmt_nested(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::String(val) => write!(f, "{:?}", val),
+            _ => write!(f, "{}", self),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::String(val) => write!(f, "{}", val),
+            Value::Int(val) => write!(f, "{}", val),
+            Value::BigInt(val) => write!(f, "{}", val),
+            Value::Float(val) => write!(f, "{}", val),
+            Value::Bool(val) => write!(f, "{}", val),
+            Value::Function(val) => write!(f, "<fn {}>", val.name()),
+            Value::Native(val) => write!(f, "<native fn {}>", val.name()),
+            Value::Class(val) => write!(f, "<class {}>", val.name),
+            Value::Instance(val) => write!(f, "<{} instance>", val.borrow().class.name),
+            Value::List(items) => fmt_guarded(f, Rc::as_ptr(items) as usize, "[...]", |f| {
+                write!(f, "[")?;
+                for (i, item) in items.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    item.fmt_nested(f)?;
+                }
+                write!(f, "]")
+            }),
+            Value::Map(map) => fmt_guarded(f, Rc::as_ptr(map) as usize, "{...}", |f| {
+                write!(f, "{{")?;
+                for (i, (key, value)) in map.borrow().entries().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    key.to_value().fmt_nested(f)?;
+                    write!(f, ": ")?;
+                    value.fmt_nested(f)?;
+                }
+                write!(f, "}}")
+            }),
+            Value::None => write!(f, "Null"),
+        }
+    }
+}
+
+// Written by hand because the derived form would recurse forever into a list
+// or map that contains itself; containers are shown as they print.
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::String(val) => write!(f, "String({:?})", val),
+            Value::Float(val) => write!(f, "Float({:?})", val),
+            Value::Int(val) => write!(f, "Int({})", val),
+            Value::BigInt(val) => write!(f, "BigInt({})", val),
+            Value::Bool(val) => write!(f, "Bool({})", val),
+            Value::Function(val) => write!(f, "Function({:?})", val),
+            Value::Native(val) => write!(f, "Native({:?})", val),
+            Value::Class(val) => write!(f, "Class({:?})", val),
+            Value::Instance(val) => write!(f, "Instance({:?})", val.borrow()),
+            Value::List(_) => write!(f, "List({})", self),
+            Value::Map(_) => write!(f, "Map({})", self),
+            Value::None => write!(f, "None"),
+        }
+    }
+}
+macro_rules! impl_op {
+    ($trait_name:ident, $method_name:ident, $op:tt, $int_op:expr, $big_op:expr, $op_name:expr, with_string) => {
+        pub trait $trait_name<RHS = Self> {
+            type Output;
+            type Error;
+            fn $method_name(self, rhs: RHS) -> Result<Self::Output, Self::Error>;
+        }
+
+        impl $trait_name for Value {
+            type Output = Value;
+            type Error = ErrorMessage;
+
+            fn $method_name(self, rhs: Value) -> Result<Value, ErrorMessage> {
+                match (self, rhs) {
+                    (Value::Int(a), Value::Int(b)) => $int_op(a, b),
+                    (Value::Int(a), Value::BigInt(b)) => $big_op(&BigInt::from(a), &b),
+                    (Value::BigInt(a), Value::Int(b)) => $big_op(&a, &BigInt::from(b)),
+                    (Value::BigInt(a), Value::BigInt(b)) => $big_op(&a, &b),
+                    (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a $op b)),
+                    (Value::Int(a), Value::Float(b)) => Ok(Value::Float(a as f64 $op b)),
+                    (Value::Float(a), Value::Int(b)) => Ok(Value::Float(a $op b as f64)),
+                    (Value::BigInt(a), Value::Float(b)) => Ok(Value::Float(a.to_f64() $op b)),
+                    (Value::Float(a), Value::BigInt(b)) => Ok(Value::Float(a $op b.to_f64())),
+                    (Value::String(a), Value::String(b)) => Ok(Value::String(a $op &b)),
+                    (Value::List(a), Value::List(b)) => {
+                        let mut items = a.borrow().clone();
+                        items.extend(b.borrow().iter().cloned());
+                        Ok(Value::new_list(items))
+                    }
+                    (l, r) => Err(ErrorMessage::new("R002", &format!(
+                        "{} not supported between {} and {}",
+                        $op_name,
+                        l.type_name(),
+                        r.type_name()
+                    ))),
+                }
+            }
+        }
+    };
+
+    ($trait_name:ident, $method_name:ident, $op:tt, $int_op:expr, $big_op:expr, $op_name:expr, with_float) => {
+        pub trait $trait_name<RHS = Self> {
+            type Output;
+            type Error;
+            fn $method_name(self, rhs: RHS) -> Result<Self::Output, Self::Error>;
+        }
+
+        impl $trait_name for Value {
+            type Output = Value;
+            type Error = ErrorMessage;
+
+             fn $method_name(self, rhs: Value) -> Result<Value, ErrorMessage> {
+                match (self, rhs) {
+                    (Value::Int(a), Value::Int(b)) => $int_op(a, b),
+                    (Value::Int(a), Value::BigInt(b)) => $big_op(&BigInt::from(a), &b),
+                    (Value::BigInt(a), Value::Int(b)) => $big_op(&a, &BigInt::from(b)),
+                    (Value::BigInt(a), Value::BigInt(b)) => $big_op(&a, &b),
+                    (Value::Float(a), Value::Float(b)) => Ok(Value::Float(a $op b)),
+                    (Value::Int(a), Value::Float(b)) => Ok(Value::Float(a as f64 $op b)),
+                    (Value::Float(a), Value::Int(b)) => Ok(Value::Float(a $op b as f64)),
+                    (Value::BigInt(a), Value::Float(b)) => Ok(Value::Float(a.to_f64() $op b)),
+                    (Value::Float(a), Value::BigInt(b)) => Ok(Value::Float(a $op b.to_f64())),
+                    (l, r) => Err(ErrorMessage::new("R002", &format!(
+                        "{} not supported between {} and {}",
+                        $op_name,
+                        l.type_name(),
+                        r.type_name()
+                    ))),
+                }
+            }
+        }
+    };
+
+    ($trait_name:ident, $method_name:ident, $op:tt, $int_op:expr, $big_op:expr, $op_name:expr, with_int) => {
+        pub trait $trait_name<RHS = Self> {
+            type Output;
+            type Error;
+            fn $method_name(self, rhs: RHS) -> Result<Self::Output, Self::Error>;
+        }
+
+        impl $trait_name for Value {
+            type Output = Value;
+            type Error = ErrorMessage;
+
+            fn $method_name(self, rhs: Value) -> Result<Value, ErrorMessage> {
+                match (self, rhs) {
+                    (Value::Int(a), Value::Int(b)) => $int_op(a, b),
+                    (Value::Int(a), Value::BigInt(b)) => $big_op(&BigInt::from(a), &b),
+                    (Value::BigInt(a), Value::Int(b)) => $big_op(&a, &BigInt::from(b)),
+                    (Value::BigInt(a), Value::BigInt(b)) => $big_op(&a, &b),
+                    (l, r) => Err(ErrorMessage::new("R002", &format!(
+                        "{} not supported between {} and {}",
+                        $op_name,
+                        l.type_name(),
+                        r.type_name()
+                    ))),
+                }
+            }
+        }
+    };
+
+    ($trait_name:ident, $method_name: ident, $op: tt, $op_name: expr, ret_bool)=>{
+        pub trait $trait_name<RHS = Self>{
+            type Output;
+            type Error;
+            fn $method_name(self, rhs: RHS) -> Result<Self::Output, Self::Error>;
+        }
+
+        impl $trait_name for Value {
+            type Output = Value;
+            type Error = ErrorMessage;
+
+            fn $method_name(self, rhs: Value) -> Result<Value, ErrorMessage> {
+                match (self, rhs) {
+                    (Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a $op b)),
+                    (Value::Float(a), Value::Float(b)) => Ok(Value::Bool(a $op b)),
+                    (Value::Int(a), Value::Float(b)) => Ok(Value::Bool((a as f64) $op b)),
+                    (Value::Float(a), Value::Int(b)) => Ok(Value::Bool(a $op b as f64)),
+                    (Value::BigInt(a), Value::BigInt(b)) => Ok(Value::Bool(*a $op *b)),
+                    (Value::Int(a), Value::BigInt(b)) => Ok(Value::Bool(BigInt::from(a) $op *b)),
+                    (Value::BigInt(a), Value::Int(b)) => Ok(Value::Bool(*a $op BigInt::from(b))),
+                    (Value::BigInt(a), Value::Float(b)) => Ok(Value::Bool(a.to_f64() $op b)),
+                    (Value::Float(a), Value::BigInt(b)) => Ok(Value::Bool(a $op b.to_f64())),
+                    (Value::String(a), Value::String(b)) => Ok(Value::Bool(a $op b)),
+                    (l, r) => Err(ErrorMessage::new("R002", &format!(
+                        "{} not supported between {} and {}",
+                        $op_name,
+                        l.type_name(),
+                        r.type_name()
+                    ))),
+                }
+            }
+        }
+    }
+}
+impl_op!(Add, add, +, int_add, big_add, "Addition", with_string);
+impl_op!(Sub, sub, -, int_sub, big_sub, "Subtraction", with_float);
+impl_op!(Div, div, /, int_div, big_div, "Division", with_float);
+impl_op!(Mul, mul, *, int_mul, big_mul, "Multiplication", with_float);
+impl_op!(Mod, modulo, %, int_mod, big_mod, "Modulo", with_float);
+impl_op!(LeftShift, left_shift, <<, int_shl, big_shl, "Left Shift", with_int);
+impl_op!(RightShift, right_shify, >>, int_shr, big_shr, "Right Shift", with_int);
+impl_op!(BitAnd, bit_and, &, |a: i64, b: i64| Ok(Value::Int(a & b)),
+    |a: &BigInt, b: &BigInt| Value::from_bigint(a.bit_and(b)), "Bit And", with_int);
+impl_op!(BitOr, bit_or, |, |a: i64, b: i64| Ok(Value::Int(a | b)),
+    |a: &BigInt, b: &BigInt| Value::from_bigint(a.bit_or(b)), "Bit Or", with_int);
+impl_op!(Xor, xor, ^, |a: i64, b: i64| Ok(Value::Int(a ^ b)),
+    |a: &BigInt, b: &BigInt| Value::from_bigint(a.bit_xor(b)), "Xor", with_int);
+impl_op!(Greater, greater, >, "Greater", ret_bool);
+impl_op!(GreaterOrEqual, greater_or_equal, >=, "Greater Or Equal", ret_bool);
+impl_op!(Lesser, lesser, <, "Lesser", ret_bool);
+impl_op!(LesserOrEqual,lesser_or_equal, <= ,"Lesser or Equal", ret_bool);
+
+// Integer arithmetic is exact: `int_*` work on `Int`s and hand over to the
+// `big_*` versions when the result doesn't fit, which give back an `Int`
+// again whenever it does. Division by zero and negative shifts are errors.
+fn too_large() -> ErrorMessage {
+    ErrorMessage::new(
+        "R020",
+        &format!(
+            "Integer result is too large, the limit is {} bits",
+            MAX_BITS
+        ),
+    )
+}
+
+fn int_add(a: i64, b: i64) -> Result<Value, ErrorMessage> {
+    match a.checked_add(b) {
+        Some(sum) => Ok(Value::Int(sum)),
+        None => big_add(&BigInt::from(a), &BigInt::from(b)),
+    }
+}
+
+fn big_add(a: &BigInt, b: &BigInt) -> Result<Value, ErrorMessage> {
+    Value::from_bigint(a.add(b))
+}
+
+fn int_sub(a: i64, b: i64) -> Result<Value, ErrorMessage> {
+    match a.checked_sub(b) {
+        Some(difference) => Ok(Value::Int(difference)),
+        None => big_sub(&BigInt::from(a), &BigInt::from(b)),
+    }
+}
+
+fn big_sub(a: &BigInt, b: &BigInt) -> Result<Value, ErrorMessage> {
+    Value::from_bigint(a.sub(b))
+}
+
+fn int_mul(a: i64, b: i64) -> Result<Value, ErrorMessage> {
+    match a.checked_mul(b) {
+        Some(product) => Ok(Value::Int(product)),
+        None => big_mul(&BigInt::from(a), &BigInt::from(b)),
+    }
+}
+
+fn big_mul(a: &BigInt, b: &BigInt) -> Result<Value, ErrorMessage> {
+    if a.bits() + b.bits() > MAX_BITS {
+        return Err(too_large());
+    }
+    Value::from_bigint(a.mul(b))
+}
+
+fn int_div(a: i64, b: i64) -> Result<Value, ErrorMessage> {
+    if b == 0 {
+        return Err(ErrorMessage::new("R019", "Division by zero"));
+    }
+    match a.checked_div(b) {
+        Some(quotient) => Ok(Value::Int(quotient)),
+        None => big_div(&BigInt::from(a), &BigInt::from(b)),
+    }
+}
+
+fn big_div(a: &BigInt, b: &BigInt) -> Result<Value, ErrorMessage> {
+    match a.div_rem(b) {
+        Some((quotient, _)) => Value::from_bigint(quotient),
+        None => Err(ErrorMessage::new("R019", "Division by zero")),
+    }
+}
+
+fn int_mod(a: i64, b: i64) -> Result<Value, ErrorMessage> {
+    if b == 0 {
+        return Err(ErrorMessage::new("R019", "Modulo by zero"));
+    }
+    // `i64::MIN % -1` overflows in the intermediate quotient only.
+    Ok(Value::Int(a.checked_rem(b).unwrap_or(0)))
+}
+
+fn big_mod(a: &BigInt, b: &BigInt) -> Result<Value, ErrorMessage> {
+    match a.div_rem(b) {
+        Some((_, remainder)) => Value::from_bigint(remainder),
+        None => Err(ErrorMessage::new("R019", "Modulo by zero")),
+    }
+}
+
+// Shift amounts too large for a `u64` saturate; only negative ones fail.
+fn shift_amount(b: &BigInt) -> Result<u64, ErrorMessage> {
+    if b.is_negative() {
+        return Err(ErrorMessage::new(
+            "R021",
+            &format!("Negative shift amount {}", b),
+        ));
+    }
+    Ok(b.to_i64().map_or(u64::MAX, |amount| amount as u64))
+}
+
+fn int_shl(a: i64, b: i64) -> Result<Value, ErrorMessage> {
+    if (0..64).contains(&b) && (a << b) >> b == a {
+        return Ok(Value::Int(a << b));
+    }
+    big_shl(&BigInt::from(a), &BigInt::from(b))
+}
+
+fn big_shl(a: &BigInt, b: &BigInt) -> Result<Value, ErrorMessage> {
+    let amount = shift_amount(b)?;
+    if a.is_zero() {
+        return Ok(Value::Int(0));
+    }
+    if a.bits().saturating_add(amount) > MAX_BITS {
+        return Err(too_large());
+    }
+    Value::from_bigint(a.shl(amount))
+}
+
+fn int_shr(a: i64, b: i64) -> Result<Value, ErrorMessage> {
+    if b < 0 {
+        return Err(ErrorMessage::new(
+            "R021",
+            &format!("Negative shift amount {}", b),
+        ));
+    }
+    // Shifting by 63 already leaves only the sign.
+    Ok(Value::Int(a >> b.min(63)))
+}
+
+fn big_shr(a: &BigInt, b: &BigInt) -> Result<Value, ErrorMessage> {
+    let amount = shift_amount(b)?;
+    Value::from_bigint(a.shr(amount))
+}
@@ -0,0 +1,1455 @@
+use std::cmp::Ordering;
+
+// The XID_Start and XID_Continue properties of Unicode 14.0.0, as listed in
+// DerivedCoreProperties.txt, stored as sorted non-overlapping ranges. The
+// tables are generated; regenerate them rather than editing by hand.
+
+// Whether `c` may start an identifier.
+pub fn is_xid_start(c: char) -> bool {
+    in_table(XID_START, c)
+}
+
+// Whether `c` may follow the first character of an identifier.
+pub fn is_xid_continue(c: char) -> bool {
+    in_table(XID_CONTINUE, c)
+}
+
+fn in_table(table: &[(char, char)], c: char) -> bool {
+    table
+        .binary_search_by(|&(start, end)| {
+            if c < start {
+                Ordering::Greater
+            } else if c > end {
+                Ordering::Less
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+#[rustfmt::skip]
+const XID_START: &[(char, char)] = &[
+    ('\u{0041}', '\u{005a}'),
+    ('\u{0061}', '\u{007a}'),
+    ('\u{00aa}', '\u{00aa}'),
+    ('\u{00b5}', '\u{00b5}'),
+    ('\u{00ba}', '\u{00ba}'),
+    ('\u{00c0}', '\u{00d6}'),
+    ('\u{00d8}', '\u{00f6}'),
+    ('\u{00f8}', '\u{02c1}'),
+    ('\u{02c6}', '\u{02d1}'),
+    ('\u{02e0}', '\u{02e4}'),
+    ('\u{02ec}', '\u{02ec}'),
+    ('\u{02ee}', '\u{02ee}'),
+    ('\u{0370}', '\u{0374}'),
+    ('\u{0376}', '\u{0377}'),
+    ('\u{037b}', '\u{037d}'),
+    ('\u{037f}', '\u{037f}'),
+    ('\u{0386}', '\u{0386}'),
+    ('\u{0388}', '\u{038a}'),
+    ('\u{038c}', '\u{038c}'),
+    ('\u{038e}', '\u{03a1}'),
+    ('\u{03a3}', '\u{03f5}'),
+    ('\u{03f7}', '\u{0481}'),
+    ('\u{048a}', '\u{052f}'),
+    ('\u{0531}', '\u{0556}'),
+    ('\u{0559}', '\u{0559}'),
+    ('\u{0560}', '\u{0588}'),
+    ('\u{05d0}', '\u{05ea}'),
+    ('\u{05ef}', '\u{05f2}'),
+    ('\u{0620}', '\u{064a}'),
+    ('\u{066e}', '\u{066f}'),
+    ('\u{0671}', '\u{06d3}'),
+    ('\u{06d5}', '\u{06d5}'),
+    ('\u{06e5}', '\u{06e6}'),
+    ('\u{06ee}', '\u{06ef}'),
+    ('\u{06fa}', '\u{06fc}'),
+    ('\u{06ff}', '\u{06ff}'),
+    ('\u{0710}', '\u{0710}'),
+    ('\u{0712}', '\u{072f}'),
+    ('\u{074d}', '\u{07a5}'),
+    ('\u{07b1}', '\u{07b1}'),
+    ('\u{07ca}', '\u{07ea}'),
+    ('\u{07f4}', '\u{07f5}'),
+    ('\u{07fa}', '\u{07fa}'),
+    ('\u{0800}', '\u{0815}'),
+    ('\u{081a}', '\u{081a}'),
+    ('\u{0824}', '\u{0824}'),
+    ('\u{0828}', '\u{0828}'),
+    ('\u{0840}', '\u{0858}'),
+    ('\u{0860}', '\u{086a}'),
+    ('\u{0870}', '\u{0887}'),
+    ('\u{0889}', '\u{088e}'),
+    ('\u{08a0}', '\u{08c9}'),
+    ('\u{0904}', '\u{0939}'),
+    ('\u{093d}', '\u{093d}'),
+    ('\u{0950}', '\u{0950}'),
+    ('\u{0958}', '\u{0961}'),
+    ('\u{0971}', '\u{0980}'),
+    ('\u{0985}', '\u{098c}'),
+    ('\u{098f}', '\u{0990}'),
+    ('\u{0993}', '\u{09a8}'),
+    ('\u{09aa}', '\u{09b0}'),
+    ('\u{09b2}', '\u{09b2}'),
+    ('\u{09b6}', '\u{09b9}'),
+    ('\u{09bd}', '\u{09bd}'),
+    ('\u{09ce}', '\u{09ce}'),
+    ('\u{09dc}', '\u{09dd}'),
+    ('\u{09df}', '\u{09e1}'),
+    ('\u{09f0}', '\u{09f1}'),
+    ('\u{09fc}', '\u{09fc}'),
+    ('\u{0a05}', '\u{0a0a}'),
+    ('\u{0a0f}', '\u{0a10}'),
+    ('\u{0a13}', '\u{0a28}'),
+    ('\u{0a2a}', '\u{0a30}'),
+    ('\u{0a32}', '\u{0a33}'),
+    ('\u{0a35}', '\u{0a36}'),
+    ('\u{0a38}', '\u{0a39}'),
+    ('\u{0a59}', '\u{0a5c}'),
+    ('\u{0a5e}', '\u{0a5e}'),
+    ('\u{0a72}', '\u{0a74}'),
+    ('\u{0a85}', '\u{0a8d}'),
+    ('\u{0a8f}', '\u{0a91}'),
+    ('\u{0a93}', '\u{0aa8}'),
+    ('\u{0aaa}', '\u{0ab0}'),
+    ('\u{0ab2}', '\u{0ab3}'),
+    ('\u{0ab5}', '\u{0ab9}'),
+    ('\u{0abd}', '\u{0abd}'),
+    ('\u{0ad0}', '\u{0ad0}'),
+    ('\u{0ae0}', '\u{0ae1}'),
+    ('\u{0af9}', '\u{0af9}'),
+    ('\u{0b05}', '\u{0b0c}'),
+    ('\u{0b0f}', '\u{0b10}'),
+    ('\u{0b13}', '\u{0b28}'),
+    ('\u{0b2a}', '\u{0b30}'),
+    ('\u{0b32}', '\u{0b33}'),
+    ('\u{0b35}', '\u{0b39}'),
+    ('\u{0b3d}', '\u{0b3d}'),
+    ('\u{0b5c}', '\u{0b5d}'),
+    ('\u{0b5f}', '\u{0b61}'),
+    ('\u{0b71}', '\u{0b71}'),
+    ('\u{0b83}', '\u{0b83}'),
+    ('\u{0b85}', '\u{0b8a}'),
+    ('\u{0b8e}', '\u{0b90}'),
+    ('\u{0b92}', '\u{0b95}'),
+    ('\u{0b99}', '\u{0b9a}'),
+    ('\u{0b9c}', '\u{0b9c}'),
+    ('\u{0b9e}', '\u{0b9f}'),
+    ('\u{0ba3}', '\u{0ba4}'),
+    ('\u{0ba8}', '\u{0baa}'),
+    ('\u{0bae}', '\u{0bb9}'),
+    ('\u{0bd0}', '\u{0bd0}'),
+    ('\u{0c05}', '\u{0c0c}'),
+    ('\u{0c0e}', '\u{0c10}'),
+    ('\u{0c12}', '\u{0c28}'),
+    ('\u{0c2a}', '\u{0c39}'),
+    ('\u{0c3d}', '\u{0c3d}'),
+    ('\u{0c58}', '\u{0c5a}'),
+    ('\u{0c5d}', '\u{0c5d}'),
+    ('\u{0c60}', '\u{0c61}'),
+    ('\u{0c80}', '\u{0c80}'),
+    ('\u{0c85}', '\u{0c8c}'),
+    ('\u{0c8e}', '\u{0c90}'),
+    ('\u{0c92}', '\u{0ca8}'),
+    ('\u{0caa}', '\u{0cb3}'),
+    ('\u{0cb5}', '\u{0cb9}'),
+    ('\u{0cbd}', '\u{0cbd}'),
+    ('\u{0cdd}', '\u{0cde}'),
+    ('\u{0ce0}', '\u{0ce1}'),
+    ('\u{0cf1}', '\u{0cf2}'),
+    ('\u{0d04}', '\u{0d0c}'),
+    ('\u{0d0e}', '\u{0d10}'),
+    ('\u{0d12}', '\u{0d3a}'),
+    ('\u{0d3d}', '\u{0d3d}'),
+    ('\u{0d4e}', '\u{0d4e}'),
+    ('\u{0d54}', '\u{0d56}'),
+    ('\u{0d5f}', '\u{0d61}'),
+    ('\u{0d7a}', '\u{0d7f}'),
+    ('\u{0d85}', '\u{0d96}'),
+    ('\u{0d9a}', '\u{0db1}'),
+    ('\u{0db3}', '\u{0dbb}'),
+    ('\u{0dbd}', '\u{0dbd}'),
+    ('\u{0dc0}', '\u{0dc6}'),
+    ('\u{0e01}', '\u{0e30}'),
+    ('\u{0e32}', '\u{0e32}'),
+    ('\u{0e40}', '\u{0e46}'),
+    ('\u{0e81}', '\u{0e82}'),
+    ('\u{0e84}', '\u{0e84}'),
+    ('\u{0e86}', '\u{0e8a}'),
+    ('\u{0e8c}', '\u{0ea3}'),
+    ('\u{0ea5}', '\u{0ea5}'),
+    ('\u{0ea7}', '\u{0eb0}'),
+    ('\u{0eb2}', '\u{0eb2}'),
+    ('\u{0ebd}', '\u{0ebd}'),
+    ('\u{0ec0}', '\u{0ec4}'),
+    ('\u{0ec6}', '\u{0ec6}'),
+    ('\u{0edc}', '\u{0edf}'),
+    ('\u{0f00}', '\u{0f00}'),
+    ('\u{0f40}', '\u{0f47}'),
+    ('\u{0f49}', '\u{0f6c}'),
+    ('\u{0f88}', '\u{0f8c}'),
+    ('\u{1000}', '\u{102a}'),
+    ('\u{103f}', '\u{103f}'),
+    ('\u{1050}', '\u{1055}'),
+    ('\u{105a}', '\u{105d}'),
+    ('\u{1061}', '\u{1061}'),
+    ('\u{1065}', '\u{1066}'),
+    ('\u{106e}', '\u{1070}'),
+    ('\u{1075}', '\u{1081}'),
+    ('\u{108e}', '\u{108e}'),
+    ('\u{10a0}', '\u{10c5}'),
+    ('\u{10c7}', '\u{10c7}'),
+    ('\u{10cd}', '\u{10cd}'),
+    ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{1248}'),
+    ('\u{124a}', '\u{124d}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128a}', '\u{128d}'),
+    ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'),
+    ('\u{12b8}', '\u{12be}'),
+    ('\u{12c0}', '\u{12c0}'),
+    ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'),
+    ('\u{12d8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135a}'),
+    ('\u{1380}', '\u{138f}'),
+    ('\u{13a0}', '\u{13f5}'),
+    ('\u{13f8}', '\u{13fd}'),
+    ('\u{1401}', '\u{166c}'),
+    ('\u{166f}', '\u{167f}'),
+    ('\u{1681}', '\u{169a}'),
+    ('\u{16a0}', '\u{16ea}'),
+    ('\u{16ee}', '\u{16f8}'),
+    ('\u{1700}', '\u{1711}'),
+    ('\u{171f}', '\u{1731}'),
+    ('\u{1740}', '\u{1751}'),
+    ('\u{1760}', '\u{176c}'),
+    ('\u{176e}', '\u{1770}'),
+    ('\u{1780}', '\u{17b3}'),
+    ('\u{17d7}', '\u{17d7}'),
+    ('\u{17dc}', '\u{17dc}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18a8}'),
+    ('\u{18aa}', '\u{18aa}'),
+    ('\u{18b0}', '\u{18f5}'),
+    ('\u{1900}', '\u{191e}'),
+    ('\u{1950}', '\u{196d}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19ab}'),
+    ('\u{19b0}', '\u{19c9}'),
+    ('\u{1a00}', '\u{1a16}'),
+    ('\u{1a20}', '\u{1a54}'),
+    ('\u{1aa7}', '\u{1aa7}'),
+    ('\u{1b05}', '\u{1b33}'),
+    ('\u{1b45}', '\u{1b4c}'),
+    ('\u{1b83}', '\u{1ba0}'),
+    ('\u{1bae}', '\u{1baf}'),
+    ('\u{1bba}', '\u{1be5}'),
+    ('\u{1c00}', '\u{1c23}'),
+    ('\u{1c4d}', '\u{1c4f}'),
+    ('\u{1c5a}', '\u{1c7d}'),
+    ('\u{1c80}', '\u{1c88}'),
+    ('\u{1c90}', '\u{1cba}'),
+    ('\u{1cbd}', '\u{1cbf}'),
+    ('\u{1ce9}', '\u{1cec}'),
+    ('\u{1cee}', '\u{1cf3}'),
+    ('\u{1cf5}', '\u{1cf6}'),
+    ('\u{1cfa}', '\u{1cfa}'),
+    ('\u{1d00}', '\u{1dbf}'),
+    ('\u{1e00}', '\u{1f15}'),
+    ('\u{1f18}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{1f57}'),
+    ('\u{1f59}', '\u{1f59}'),
+    ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'),
+    ('\u{1f5f}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1fb4}'),
+    ('\u{1fb6}', '\u{1fbc}'),
+    ('\u{1fbe}', '\u{1fbe}'),
+    ('\u{1fc2}', '\u{1fc4}'),
+    ('\u{1fc6}', '\u{1fcc}'),
+    ('\u{1fd0}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'),
+    ('\u{1fe0}', '\u{1fec}'),
+    ('\u{1ff2}', '\u{1ff4}'),
+    ('\u{1ff6}', '\u{1ffc}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207f}', '\u{207f}'),
+    ('\u{2090}', '\u{209c}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210a}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211d}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212a}', '\u{2139}'),
+    ('\u{213c}', '\u{213f}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214e}', '\u{214e}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2c00}', '\u{2ce4}'),
+    ('\u{2ceb}', '\u{2cee}'),
+    ('\u{2cf2}', '\u{2cf3}'),
+    ('\u{2d00}', '\u{2d25}'),
+    ('\u{2d27}', '\u{2d27}'),
+    ('\u{2d2d}', '\u{2d2d}'),
+    ('\u{2d30}', '\u{2d67}'),
+    ('\u{2d6f}', '\u{2d6f}'),
+    ('\u{2d80}', '\u{2d96}'),
+    ('\u{2da0}', '\u{2da6}'),
+    ('\u{2da8}', '\u{2dae}'),
+    ('\u{2db0}', '\u{2db6}'),
+    ('\u{2db8}', '\u{2dbe}'),
+    ('\u{2dc0}', '\u{2dc6}'),
+    ('\u{2dc8}', '\u{2dce}'),
+    ('\u{2dd0}', '\u{2dd6}'),
+    ('\u{2dd8}', '\u{2dde}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303c}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309d}', '\u{309f}'),
+    ('\u{30a1}', '\u{30fa}'),
+    ('\u{30fc}', '\u{30ff}'),
+    ('\u{3105}', '\u{312f}'),
+    ('\u{3131}', '\u{318e}'),
+    ('\u{31a0}', '\u{31bf}'),
+    ('\u{31f0}', '\u{31ff}'),
+    ('\u{3400}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{a48c}'),
+    ('\u{a4d0}', '\u{a4fd}'),
+    ('\u{a500}', '\u{a60c}'),
+    ('\u{a610}', '\u{a61f}'),
+    ('\u{a62a}', '\u{a62b}'),
+    ('\u{a640}', '\u{a66e}'),
+    ('\u{a67f}', '\u{a69d}'),
+    ('\u{a6a0}', '\u{a6ef}'),
+    ('\u{a717}', '\u{a71f}'),
+    ('\u{a722}', '\u{a788}'),
+    ('\u{a78b}', '\u{a7ca}'),
+    ('\u{a7d0}', '\u{a7d1}'),
+    ('\u{a7d3}', '\u{a7d3}'),
+    ('\u{a7d5}', '\u{a7d9}'),
+    ('\u{a7f2}', '\u{a801}'),
+    ('\u{a803}', '\u{a805}'),
+    ('\u{a807}', '\u{a80a}'),
+    ('\u{a80c}', '\u{a822}'),
+    ('\u{a840}', '\u{a873}'),
+    ('\u{a882}', '\u{a8b3}'),
+    ('\u{a8f2}', '\u{a8f7}'),
+    ('\u{a8fb}', '\u{a8fb}'),
+    ('\u{a8fd}', '\u{a8fe}'),
+    ('\u{a90a}', '\u{a925}'),
+    ('\u{a930}', '\u{a946}'),
+    ('\u{a960}', '\u{a97c}'),
+    ('\u{a984}', '\u{a9b2}'),
+    ('\u{a9cf}', '\u{a9cf}'),
+    ('\u{a9e0}', '\u{a9e4}'),
+    ('\u{a9e6}', '\u{a9ef}'),
+    ('\u{a9fa}', '\u{a9fe}'),
+    ('\u{aa00}', '\u{aa28}'),
+    ('\u{aa40}', '\u{aa42}'),
+    ('\u{aa44}', '\u{aa4b}'),
+    ('\u{aa60}', '\u{aa76}'),
+    ('\u{aa7a}', '\u{aa7a}'),
+    ('\u{aa7e}', '\u{aaaf}'),
+    ('\u{aab1}', '\u{aab1}'),
+    ('\u{aab5}', '\u{aab6}'),
+    ('\u{aab9}', '\u{aabd}'),
+    ('\u{aac0}', '\u{aac0}'),
+    ('\u{aac2}', '\u{aac2}'),
+    ('\u{aadb}', '\u{aadd}'),
+    ('\u{aae0}', '\u{aaea}'),
+    ('\u{aaf2}', '\u{aaf4}'),
+    ('\u{ab01}', '\u{ab06}'),
+    ('\u{ab09}', '\u{ab0e}'),
+    ('\u{ab11}', '\u{ab16}'),
+    ('\u{ab20}', '\u{ab26}'),
+    ('\u{ab28}', '\u{ab2e}'),
+    ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab5c}', '\u{ab69}'),
+    ('\u{ab70}', '\u{abe2}'),
+    ('\u{ac00}', '\u{d7a3}'),
+    ('\u{d7b0}', '\u{d7c6}'),
+    ('\u{d7cb}', '\u{d7fb}'),
+    ('\u{f900}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'),
+    ('\u{fb00}', '\u{fb06}'),
+    ('\u{fb13}', '\u{fb17}'),
+    ('\u{fb1d}', '\u{fb1d}'),
+    ('\u{fb1f}', '\u{fb28}'),
+    ('\u{fb2a}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'),
+    ('\u{fb3e}', '\u{fb3e}'),
+    ('\u{fb40}', '\u{fb41}'),
+    ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fbb1}'),
+    ('\u{fbd3}', '\u{fc5d}'),
+    ('\u{fc64}', '\u{fd3d}'),
+    ('\u{fd50}', '\u{fd8f}'),
+    ('\u{fd92}', '\u{fdc7}'),
+    ('\u{fdf0}', '\u{fdf9}'),
+    ('\u{fe71}', '\u{fe71}'),
+    ('\u{fe73}', '\u{fe73}'),
+    ('\u{fe77}', '\u{fe77}'),
+    ('\u{fe79}', '\u{fe79}'),
+    ('\u{fe7b}', '\u{fe7b}'),
+    ('\u{fe7d}', '\u{fe7d}'),
+    ('\u{fe7f}', '\u{fefc}'),
+    ('\u{ff21}', '\u{ff3a}'),
+    ('\u{ff41}', '\u{ff5a}'),
+    ('\u{ff66}', '\u{ff9d}'),
+    ('\u{ffa0}', '\u{ffbe}'),
+    ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'),
+    ('\u{ffd2}', '\u{ffd7}'),
+    ('\u{ffda}', '\u{ffdc}'),
+    ('\u{10000}', '\u{1000b}'),
+    ('\u{1000d}', '\u{10026}'),
+    ('\u{10028}', '\u{1003a}'),
+    ('\u{1003c}', '\u{1003d}'),
+    ('\u{1003f}', '\u{1004d}'),
+    ('\u{10050}', '\u{1005d}'),
+    ('\u{10080}', '\u{100fa}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{10280}', '\u{1029c}'),
+    ('\u{102a0}', '\u{102d0}'),
+    ('\u{10300}', '\u{1031f}'),
+    ('\u{1032d}', '\u{1034a}'),
+    ('\u{10350}', '\u{10375}'),
+    ('\u{10380}', '\u{1039d}'),
+    ('\u{103a0}', '\u{103c3}'),
+    ('\u{103c8}', '\u{103cf}'),
+    ('\u{103d1}', '\u{103d5}'),
+    ('\u{10400}', '\u{1049d}'),
+    ('\u{104b0}', '\u{104d3}'),
+    ('\u{104d8}', '\u{104fb}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057a}'),
+    ('\u{1057c}', '\u{1058a}'),
+    ('\u{1058c}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105a1}'),
+    ('\u{105a3}', '\u{105b1}'),
+    ('\u{105b3}', '\u{105b9}'),
+    ('\u{105bb}', '\u{105bc}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107b0}'),
+    ('\u{107b2}', '\u{107ba}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080a}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083c}', '\u{1083c}'),
+    ('\u{1083f}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089e}'),
+    ('\u{108e0}', '\u{108f2}'),
+    ('\u{108f4}', '\u{108f5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109b7}'),
+    ('\u{109be}', '\u{109bf}'),
+    ('\u{10a00}', '\u{10a00}'),
+    ('\u{10a10}', '\u{10a13}'),
+    ('\u{10a15}', '\u{10a17}'),
+    ('\u{10a19}', '\u{10a35}'),
+    ('\u{10a60}', '\u{10a7c}'),
+    ('\u{10a80}', '\u{10a9c}'),
+    ('\u{10ac0}', '\u{10ac7}'),
+    ('\u{10ac9}', '\u{10ae4}'),
+    ('\u{10b00}', '\u{10b35}'),
+    ('\u{10b40}', '\u{10b55}'),
+    ('\u{10b60}', '\u{10b72}'),
+    ('\u{10b80}', '\u{10b91}'),
+    ('\u{10c00}', '\u{10c48}'),
+    ('\u{10c80}', '\u{10cb2}'),
+    ('\u{10cc0}', '\u{10cf2}'),
+    ('\u{10d00}', '\u{10d23}'),
+    ('\u{10e80}', '\u{10ea9}'),
+    ('\u{10eb0}', '\u{10eb1}'),
+    ('\u{10f00}', '\u{10f1c}'),
+    ('\u{10f27}', '\u{10f27}'),
+    ('\u{10f30}', '\u{10f45}'),
+    ('\u{10f70}', '\u{10f81}'),
+    ('\u{10fb0}', '\u{10fc4}'),
+    ('\u{10fe0}', '\u{10ff6}'),
+    ('\u{11003}', '\u{11037}'),
+    ('\u{11071}', '\u{11072}'),
+    ('\u{11075}', '\u{11075}'),
+    ('\u{11083}', '\u{110af}'),
+    ('\u{110d0}', '\u{110e8}'),
+    ('\u{11103}', '\u{11126}'),
+    ('\u{11144}', '\u{11144}'),
+    ('\u{11147}', '\u{11147}'),
+    ('\u{11150}', '\u{11172}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11183}', '\u{111b2}'),
+    ('\u{111c1}', '\u{111c4}'),
+    ('\u{111da}', '\u{111da}'),
+    ('\u{111dc}', '\u{111dc}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{1122b}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128a}', '\u{1128d}'),
+    ('\u{1128f}', '\u{1129d}'),
+    ('\u{1129f}', '\u{112a8}'),
+    ('\u{112b0}', '\u{112de}'),
+    ('\u{11305}', '\u{1130c}'),
+    ('\u{1130f}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132a}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133d}', '\u{1133d}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{1135d}', '\u{11361}'),
+    ('\u{11400}', '\u{11434}'),
+    ('\u{11447}', '\u{1144a}'),
+    ('\u{1145f}', '\u{11461}'),
+    ('\u{11480}', '\u{114af}'),
+    ('\u{114c4}', '\u{114c5}'),
+    ('\u{114c7}', '\u{114c7}'),
+    ('\u{11580}', '\u{115ae}'),
+    ('\u{115d8}', '\u{115db}'),
+    ('\u{11600}', '\u{1162f}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11680}', '\u{116aa}'),
+    ('\u{116b8}', '\u{116b8}'),
+    ('\u{11700}', '\u{1171a}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1182b}'),
+    ('\u{118a0}', '\u{118df}'),
+    ('\u{118ff}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190c}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{1192f}'),
+    ('\u{1193f}', '\u{1193f}'),
+    ('\u{11941}', '\u{11941}'),
+    ('\u{119a0}', '\u{119a7}'),
+    ('\u{119aa}', '\u{119d0}'),
+    ('\u{119e1}', '\u{119e1}'),
+    ('\u{119e3}', '\u{119e3}'),
+    ('\u{11a00}', '\u{11a00}'),
+    ('\u{11a0b}', '\u{11a32}'),
+    ('\u{11a3a}', '\u{11a3a}'),
+    ('\u{11a50}', '\u{11a50}'),
+    ('\u{11a5c}', '\u{11a89}'),
+    ('\u{11a9d}', '\u{11a9d}'),
+    ('\u{11ab0}', '\u{11af8}'),
+    ('\u{11c00}', '\u{11c08}'),
+    ('\u{11c0a}', '\u{11c2e}'),
+    ('\u{11c40}', '\u{11c40}'),
+    ('\u{11c72}', '\u{11c8f}'),
+    ('\u{11d00}', '\u{11d06}'),
+    ('\u{11d08}', '\u{11d09}'),
+    ('\u{11d0b}', '\u{11d30}'),
+    ('\u{11d46}', '\u{11d46}'),
+    ('\u{11d60}', '\u{11d65}'),
+    ('\u{11d67}', '\u{11d68}'),
+    ('\u{11d6a}', '\u{11d89}'),
+    ('\u{11d98}', '\u{11d98}'),
+    ('\u{11ee0}', '\u{11ef2}'),
+    ('\u{11fb0}', '\u{11fb0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246e}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12f90}', '\u{12ff0}'),
+    ('\u{13000}', '\u{1342e}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16a38}'),
+    ('\u{16a40}', '\u{16a5e}'),
+    ('\u{16a70}', '\u{16abe}'),
+    ('\u{16ad0}', '\u{16aed}'),
+    ('\u{16b00}', '\u{16b2f}'),
+    ('\u{16b40}', '\u{16b43}'),
+    ('\u{16b63}', '\u{16b77}'),
+    ('\u{16b7d}', '\u{16b8f}'),
+    ('\u{16e40}', '\u{16e7f}'),
+    ('\u{16f00}', '\u{16f4a}'),
+    ('\u{16f50}', '\u{16f50}'),
+    ('\u{16f93}', '\u{16f9f}'),
+    ('\u{16fe0}', '\u{16fe1}'),
+    ('\u{16fe3}', '\u{16fe3}'),
+    ('\u{17000}', '\u{187f7}'),
+    ('\u{18800}', '\u{18cd5}'),
+    ('\u{18d00}', '\u{18d08}'),
+    ('\u{1aff0}', '\u{1aff3}'),
+    ('\u{1aff5}', '\u{1affb}'),
+    ('\u{1affd}', '\u{1affe}'),
+    ('\u{1b000}', '\u{1b122}'),
+    ('\u{1b150}', '\u{1b152}'),
+    ('\u{1b164}', '\u{1b167}'),
+    ('\u{1b170}', '\u{1b2fb}'),
+    ('\u{1bc00}', '\u{1bc6a}'),
+    ('\u{1bc70}', '\u{1bc7c}'),
+    ('\u{1bc80}', '\u{1bc88}'),
+    ('\u{1bc90}', '\u{1bc99}'),
+    ('\u{1d400}', '\u{1d454}'),
+    ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'),
+    ('\u{1d4a5}', '\u{1d4a6}'),
+    ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'),
+    ('\u{1d4bd}', '\u{1d4c3}'),
+    ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'),
+    ('\u{1d516}', '\u{1d51c}'),
+    ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'),
+    ('\u{1d546}', '\u{1d546}'),
+    ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d6c0}'),
+    ('\u{1d6c2}', '\u{1d6da}'),
+    ('\u{1d6dc}', '\u{1d6fa}'),
+    ('\u{1d6fc}', '\u{1d714}'),
+    ('\u{1d716}', '\u{1d734}'),
+    ('\u{1d736}', '\u{1d74e}'),
+    ('\u{1d750}', '\u{1d76e}'),
+    ('\u{1d770}', '\u{1d788}'),
+    ('\u{1d78a}', '\u{1d7a8}'),
+    ('\u{1d7aa}', '\u{1d7c2}'),
+    ('\u{1d7c4}', '\u{1d7cb}'),
+    ('\u{1df00}', '\u{1df1e}'),
+    ('\u{1e100}', '\u{1e12c}'),
+    ('\u{1e137}', '\u{1e13d}'),
+    ('\u{1e14e}', '\u{1e14e}'),
+    ('\u{1e290}', '\u{1e2ad}'),
+    ('\u{1e2c0}', '\u{1e2eb}'),
+    ('\u{1e7e0}', '\u{1e7e6}'),
+    ('\u{1e7e8}', '\u{1e7eb}'),
+    ('\u{1e7ed}', '\u{1e7ee}'),
+    ('\u{1e7f0}', '\u{1e7fe}'),
+    ('\u{1e800}', '\u{1e8c4}'),
+    ('\u{1e900}', '\u{1e943}'),
+    ('\u{1e94b}', '\u{1e94b}'),
+    ('\u{1ee00}', '\u{1ee03}'),
+    ('\u{1ee05}', '\u{1ee1f}'),
+    ('\u{1ee21}', '\u{1ee22}'),
+    ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'),
+    ('\u{1ee29}', '\u{1ee32}'),
+    ('\u{1ee34}', '\u{1ee37}'),
+    ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'),
+    ('\u{1ee42}', '\u{1ee42}'),
+    ('\u{1ee47}', '\u{1ee47}'),
+    ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'),
+    ('\u{1ee4d}', '\u{1ee4f}'),
+    ('\u{1ee51}', '\u{1ee52}'),
+    ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'),
+    ('\u{1ee59}', '\u{1ee59}'),
+    ('\u{1ee5b}', '\u{1ee5b}'),
+    ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'),
+    ('\u{1ee61}', '\u{1ee62}'),
+    ('\u{1ee64}', '\u{1ee64}'),
+    ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'),
+    ('\u{1ee74}', '\u{1ee77}'),
+    ('\u{1ee79}', '\u{1ee7c}'),
+    ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'),
+    ('\u{1ee8b}', '\u{1ee9b}'),
+    ('\u{1eea1}', '\u{1eea3}'),
+    ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'),
+    ('\u{20000}', '\u{2a6df}'),
+    ('\u{2a700}', '\u{2b738}'),
+    ('\u{2b740}', '\u{2b81d}'),
+    ('\u{2b820}', '\u{2cea1}'),
+    ('\u{2ceb0}', '\u{2ebe0}'),
+    ('\u{2f800}', '\u{2fa1d}'),
+    ('\u{30000}', '\u{3134a}'),
+];
+
+#[rustfmt::skip]
+const XID_CONTINUE: &[(char, char)] = &[
+    ('\u{0030}', '\u{0039}'),
+    ('\u{0041}', '\u{005a}'),
+    ('\u{005f}', '\u{005f}'),
+    ('\u{0061}', '\u{007a}'),
+    ('\u{00aa}', '\u{00aa}'),
+    ('\u{00b5}', '\u{00b5}'),
+    ('\u{00b7}', '\u{00b7}'),
+    ('\u{00ba}', '\u{00ba}'),
+    ('\u{00c0}', '\u{00d6}'),
+    ('\u{00d8}', '\u{00f6}'),
+    ('\u{00f8}', '\u{02c1}'),
+    ('\u{02c6}', '\u{02d1}'),
+    ('\u{02e0}', '\u{02e4}'),
+    ('\u{02ec}', '\u{02ec}'),
+    ('\u{02ee}', '\u{02ee}'),
+    ('\u{0300}', '\u{0374}'),
+    ('\u{0376}', '\u{0377}'),
+    ('\u{037b}', '\u{037d}'),
+    ('\u{037f}', '\u{037f}'),
+    ('\u{0386}', '\u{038a}'),
+    ('\u{038c}', '\u{038c}'),
+    ('\u{038e}', '\u{03a1}'),
+    ('\u{03a3}', '\u{03f5}'),
+    ('\u{03f7}', '\u{0481}'),
+    ('\u{0483}', '\u{0487}'),
+    ('\u{048a}', '\u{052f}'),
+    ('\u{0531}', '\u{0556}'),
+    ('\u{0559}', '\u{0559}'),
+    ('\u{0560}', '\u{0588}'),
+    ('\u{0591}', '\u{05bd}'),
+    ('\u{05bf}', '\u{05bf}'),
+    ('\u{05c1}', '\u{05c2}'),
+    ('\u{05c4}', '\u{05c5}'),
+    ('\u{05c7}', '\u{05c7}'),
+    ('\u{05d0}', '\u{05ea}'),
+    ('\u{05ef}', '\u{05f2}'),
+    ('\u{0610}', '\u{061a}'),
+    ('\u{0620}', '\u{0669}'),
+    ('\u{066e}', '\u{06d3}'),
+    ('\u{06d5}', '\u{06dc}'),
+    ('\u{06df}', '\u{06e8}'),
+    ('\u{06ea}', '\u{06fc}'),
+    ('\u{06ff}', '\u{06ff}'),
+    ('\u{0710}', '\u{074a}'),
+    ('\u{074d}', '\u{07b1}'),
+    ('\u{07c0}', '\u{07f5}'),
+    ('\u{07fa}', '\u{07fa}'),
+    ('\u{07fd}', '\u{07fd}'),
+    ('\u{0800}', '\u{082d}'),
+    ('\u{0840}', '\u{085b}'),
+    ('\u{0860}', '\u{086a}'),
+    ('\u{0870}', '\u{0887}'),
+    ('\u{0889}', '\u{088e}'),
+    ('\u{0898}', '\u{08e1}'),
+    ('\u{08e3}', '\u{0963}'),
+    ('\u{0966}', '\u{096f}'),
+    ('\u{0971}', '\u{0983}'),
+    ('\u{0985}', '\u{098c}'),
+    ('\u{098f}', '\u{0990}'),
+    ('\u{0993}', '\u{09a8}'),
+    ('\u{09aa}', '\u{09b0}'),
+    ('\u{09b2}', '\u{09b2}'),
+    ('\u{09b6}', '\u{09b9}'),
+    ('\u{09bc}', '\u{09c4}'),
+    ('\u{09c7}', '\u{09c8}'),
+    ('\u{09cb}', '\u{09ce}'),
+    ('\u{09d7}', '\u{09d7}'),
+    ('\u{09dc}', '\u{09dd}'),
+    ('\u{09df}', '\u{09e3}'),
+    ('\u{09e6}', '\u{09f1}'),
+    ('\u{09fc}', '\u{09fc}'),
+    ('\u{09fe}', '\u{09fe}'),
+    ('\u{0a01}', '\u{0a03}'),
+    ('\u{0a05}', '\u{0a0a}'),
+    ('\u{0a0f}', '\u{0a10}'),
+    ('\u{0a13}', '\u{0a28}'),
+    ('\u{0a2a}', '\u{0a30}'),
+    ('\u{0a32}', '\u{0a33}'),
+    ('\u{0a35}', '\u{0a36}'),
+    ('\u{0a38}', '\u{0a39}'),
+    ('\u{0a3c}', '\u{0a3c}'),
+    ('\u{0a3e}', '\u{0a42}'),
+    ('\u{0a47}', '\u{0a48}'),
+    ('\u{0a4b}', '\u{0a4d}'),
+    ('\u{0a51}', '\u{0a51}'),
+    ('\u{0a59}', '\u{0a5c}'),
+    ('\u{0a5e}', '\u{0a5e}'),
+    ('\u{0a66}', '\u{0a75}'),
+    ('\u{0a81}', '\u{0a83}'),
+    ('\u{0a85}', '\u{0a8d}'),
+    ('\u{0a8f}', '\u{0a91}'),
+    ('\u{0a93}', '\u{0aa8}'),
+    ('\u{0aaa}', '\u{0ab0}'),
+    ('\u{0ab2}', '\u{0ab3}'),
+    ('\u{0ab5}', '\u{0ab9}'),
+    ('\u{0abc}', '\u{0ac5}'),
+    ('\u{0ac7}', '\u{0ac9}'),
+    ('\u{0acb}', '\u{0acd}'),
+    ('\u{0ad0}', '\u{0ad0}'),
+    ('\u{0ae0}', '\u{0ae3}'),
+    ('\u{0ae6}', '\u{0aef}'),
+    ('\u{0af9}', '\u{0aff}'),
+    ('\u{0b01}', '\u{0b03}'),
+    ('\u{0b05}', '\u{0b0c}'),
+    ('\u{0b0f}', '\u{0b10}'),
+    ('\u{0b13}', '\u{0b28}'),
+    ('\u{0b2a}', '\u{0b30}'),
+    ('\u{0b32}', '\u{0b33}'),
+    ('\u{0b35}', '\u{0b39}'),
+    ('\u{0b3c}', '\u{0b44}'),
+    ('\u{0b47}', '\u{0b48}'),
+    ('\u{0b4b}', '\u{0b4d}'),
+    ('\u{0b55}', '\u{0b57}'),
+    ('\u{0b5c}', '\u{0b5d}'),
+    ('\u{0b5f}', '\u{0b63}'),
+    ('\u{0b66}', '\u{0b6f}'),
+    ('\u{0b71}', '\u{0b71}'),
+    ('\u{0b82}', '\u{0b83}'),
+    ('\u{0b85}', '\u{0b8a}'),
+    ('\u{0b8e}', '\u{0b90}'),
+    ('\u{0b92}', '\u{0b95}'),
+    ('\u{0b99}', '\u{0b9a}'),
+    ('\u{0b9c}', '\u{0b9c}'),
+    ('\u{0b9e}', '\u{0b9f}'),
+    ('\u{0ba3}', '\u{0ba4}'),
+    ('\u{0ba8}', '\u{0baa}'),
+    ('\u{0bae}', '\u{0bb9}'),
+    ('\u{0bbe}', '\u{0bc2}'),
+    ('\u{0bc6}', '\u{0bc8}'),
+    ('\u{0bca}', '\u{0bcd}'),
+    ('\u{0bd0}', '\u{0bd0}'),
+    ('\u{0bd7}', '\u{0bd7}'),
+    ('\u{0be6}', '\u{0bef}'),
+    ('\u{0c00}', '\u{0c0c}'),
+    ('\u{0c0e}', '\u{0c10}'),
+    ('\u{0c12}', '\u{0c28}'),
+    ('\u{0c2a}', '\u{0c39}'),
+    ('\u{0c3c}', '\u{0c44}'),
+    ('\u{0c46}', '\u{0c48}'),
+    ('\u{0c4a}', '\u{0c4d}'),
+    ('\u{0c55}', '\u{0c56}'),
+    ('\u{0c58}', '\u{0c5a}'),
+    ('\u{0c5d}', '\u{0c5d}'),
+    ('\u{0c60}', '\u{0c63}'),
+    ('\u{0c66}', '\u{0c6f}'),
+    ('\u{0c80}', '\u{0c83}'),
+    ('\u{0c85}', '\u{0c8c}'),
+    ('\u{0c8e}', '\u{0c90}'),
+    ('\u{0c92}', '\u{0ca8}'),
+    ('\u{0caa}', '\u{0cb3}'),
+    ('\u{0cb5}', '\u{0cb9}'),
+    ('\u{0cbc}', '\u{0cc4}'),
+    ('\u{0cc6}', '\u{0cc8}'),
+    ('\u{0cca}', '\u{0ccd}'),
+    ('\u{0cd5}', '\u{0cd6}'),
+    ('\u{0cdd}', '\u{0cde}'),
+    ('\u{0ce0}', '\u{0ce3}'),
+    ('\u{0ce6}', '\u{0cef}'),
+    ('\u{0cf1}', '\u{0cf2}'),
+    ('\u{0d00}', '\u{0d0c}'),
+    ('\u{0d0e}', '\u{0d10}'),
+    ('\u{0d12}', '\u{0d44}'),
+    ('\u{0d46}', '\u{0d48}'),
+    ('\u{0d4a}', '\u{0d4e}'),
+    ('\u{0d54}', '\u{0d57}'),
+    ('\u{0d5f}', '\u{0d63}'),
+    ('\u{0d66}', '\u{0d6f}'),
+    ('\u{0d7a}', '\u{0d7f}'),
+    ('\u{0d81}', '\u{0d83}'),
+    ('\u{0d85}', '\u{0d96}'),
+    ('\u{0d9a}', '\u{0db1}'),
+    ('\u{0db3}', '\u{0dbb}'),
+    ('\u{0dbd}', '\u{0dbd}'),
+    ('\u{0dc0}', '\u{0dc6}'),
+    ('\u{0dca}', '\u{0dca}'),
+    ('\u{0dcf}', '\u{0dd4}'),
+    ('\u{0dd6}', '\u{0dd6}'),
+    ('\u{0dd8}', '\u{0ddf}'),
+    ('\u{0de6}', '\u{0def}'),
+    ('\u{0df2}', '\u{0df3}'),
+    ('\u{0e01}', '\u{0e3a}'),
+    ('\u{0e40}', '\u{0e4e}'),
+    ('\u{0e50}', '\u{0e59}'),
+    ('\u{0e81}', '\u{0e82}'),
+    ('\u{0e84}', '\u{0e84}'),
+    ('\u{0e86}', '\u{0e8a}'),
+    ('\u{0e8c}', '\u{0ea3}'),
+    ('\u{0ea5}', '\u{0ea5}'),
+    ('\u{0ea7}', '\u{0ebd}'),
+    ('\u{0ec0}', '\u{0ec4}'),
+    ('\u{0ec6}', '\u{0ec6}'),
+    ('\u{0ec8}', '\u{0ecd}'),
+    ('\u{0ed0}', '\u{0ed9}'),
+    ('\u{0edc}', '\u{0edf}'),
+    ('\u{0f00}', '\u{0f00}'),
+    ('\u{0f18}', '\u{0f19}'),
+    ('\u{0f20}', '\u{0f29}'),
+    ('\u{0f35}', '\u{0f35}'),
+    ('\u{0f37}', '\u{0f37}'),
+    ('\u{0f39}', '\u{0f39}'),
+    ('\u{0f3e}', '\u{0f47}'),
+    ('\u{0f49}', '\u{0f6c}'),
+    ('\u{0f71}', '\u{0f84}'),
+    ('\u{0f86}', '\u{0f97}'),
+    ('\u{0f99}', '\u{0fbc}'),
+    ('\u{0fc6}', '\u{0fc6}'),
+    ('\u{1000}', '\u{1049}'),
+    ('\u{1050}', '\u{109d}'),
+    ('\u{10a0}', '\u{10c5}'),
+    ('\u{10c7}', '\u{10c7}'),
+    ('\u{10cd}', '\u{10cd}'),
+    ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{1248}'),
+    ('\u{124a}', '\u{124d}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128a}', '\u{128d}'),
+    ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'),
+    ('\u{12b8}', '\u{12be}'),
+    ('\u{12c0}', '\u{12c0}'),
+    ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'),
+    ('\u{12d8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135a}'),
+    ('\u{135d}', '\u{135f}'),
+    ('\u{1369}', '\u{1371}'),
+    ('\u{1380}', '\u{138f}'),
+    ('\u{13a0}', '\u{13f5}'),
+    ('\u{13f8}', '\u{13fd}'),
+    ('\u{1401}', '\u{166c}'),
+    ('\u{166f}', '\u{167f}'),
+    ('\u{1681}', '\u{169a}'),
+    ('\u{16a0}', '\u{16ea}'),
+    ('\u{16ee}', '\u{16f8}'),
+    ('\u{1700}', '\u{1715}'),
+    ('\u{171f}', '\u{1734}'),
+    ('\u{1740}', '\u{1753}'),
+    ('\u{1760}', '\u{176c}'),
+    ('\u{176e}', '\u{1770}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{1780}', '\u{17d3}'),
+    ('\u{17d7}', '\u{17d7}'),
+    ('\u{17dc}', '\u{17dd}'),
+    ('\u{17e0}', '\u{17e9}'),
+    ('\u{180b}', '\u{180d}'),
+    ('\u{180f}', '\u{1819}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{18aa}'),
+    ('\u{18b0}', '\u{18f5}'),
+    ('\u{1900}', '\u{191e}'),
+    ('\u{1920}', '\u{192b}'),
+    ('\u{1930}', '\u{193b}'),
+    ('\u{1946}', '\u{196d}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19ab}'),
+    ('\u{19b0}', '\u{19c9}'),
+    ('\u{19d0}', '\u{19da}'),
+    ('\u{1a00}', '\u{1a1b}'),
+    ('\u{1a20}', '\u{1a5e}'),
+    ('\u{1a60}', '\u{1a7c}'),
+    ('\u{1a7f}', '\u{1a89}'),
+    ('\u{1a90}', '\u{1a99}'),
+    ('\u{1aa7}', '\u{1aa7}'),
+    ('\u{1ab0}', '\u{1abd}'),
+    ('\u{1abf}', '\u{1ace}'),
+    ('\u{1b00}', '\u{1b4c}'),
+    ('\u{1b50}', '\u{1b59}'),
+    ('\u{1b6b}', '\u{1b73}'),
+    ('\u{1b80}', '\u{1bf3}'),
+    ('\u{1c00}', '\u{1c37}'),
+    ('\u{1c40}', '\u{1c49}'),
+    ('\u{1c4d}', '\u{1c7d}'),
+    ('\u{1c80}', '\u{1c88}'),
+    ('\u{1c90}', '\u{1cba}'),
+    ('\u{1cbd}', '\u{1cbf}'),
+    ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1cfa}'),
+    ('\u{1d00}', '\u{1f15}'),
+    ('\u{1f18}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{1f57}'),
+    ('\u{1f59}', '\u{1f59}'),
+    ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'),
+    ('\u{1f5f}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1fb4}'),
+    ('\u{1fb6}', '\u{1fbc}'),
+    ('\u{1fbe}', '\u{1fbe}'),
+    ('\u{1fc2}', '\u{1fc4}'),
+    ('\u{1fc6}', '\u{1fcc}'),
+    ('\u{1fd0}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'),
+    ('\u{1fe0}', '\u{1fec}'),
+    ('\u{1ff2}', '\u{1ff4}'),
+    ('\u{1ff6}', '\u{1ffc}'),
+    ('\u{203f}', '\u{2040}'),
+    ('\u{2054}', '\u{2054}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207f}', '\u{207f}'),
+    ('\u{2090}', '\u{209c}'),
+    ('\u{20d0}', '\u{20dc}'),
+    ('\u{20e1}', '\u{20e1}'),
+    ('\u{20e5}', '\u{20f0}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210a}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2118}', '\u{211d}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212a}', '\u{2139}'),
+    ('\u{213c}', '\u{213f}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214e}', '\u{214e}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2c00}', '\u{2ce4}'),
+    ('\u{2ceb}', '\u{2cf3}'),
+    ('\u{2d00}', '\u{2d25}'),
+    ('\u{2d27}', '\u{2d27}'),
+    ('\u{2d2d}', '\u{2d2d}'),
+    ('\u{2d30}', '\u{2d67}'),
+    ('\u{2d6f}', '\u{2d6f}'),
+    ('\u{2d7f}', '\u{2d96}'),
+    ('\u{2da0}', '\u{2da6}'),
+    ('\u{2da8}', '\u{2dae}'),
+    ('\u{2db0}', '\u{2db6}'),
+    ('\u{2db8}', '\u{2dbe}'),
+    ('\u{2dc0}', '\u{2dc6}'),
+    ('\u{2dc8}', '\u{2dce}'),
+    ('\u{2dd0}', '\u{2dd6}'),
+    ('\u{2dd8}', '\u{2dde}'),
+    ('\u{2de0}', '\u{2dff}'),
+    ('\u{3005}', '\u{3007}'),
+    ('\u{3021}', '\u{302f}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{3038}', '\u{303c}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{3099}', '\u{309a}'),
+    ('\u{309d}', '\u{309f}'),
+    ('\u{30a1}', '\u{30fa}'),
+    ('\u{30fc}', '\u{30ff}'),
+    ('\u{3105}', '\u{312f}'),
+    ('\u{3131}', '\u{318e}'),
+    ('\u{31a0}', '\u{31bf}'),
+    ('\u{31f0}', '\u{31ff}'),
+    ('\u{3400}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{a48c}'),
+    ('\u{a4d0}', '\u{a4fd}'),
+    ('\u{a500}', '\u{a60c}'),
+    ('\u{a610}', '\u{a62b}'),
+    ('\u{a640}', '\u{a66f}'),
+    ('\u{a674}', '\u{a67d}'),
+    ('\u{a67f}', '\u{a6f1}'),
+    ('\u{a717}', '\u{a71f}'),
+    ('\u{a722}', '\u{a788}'),
+    ('\u{a78b}', '\u{a7ca}'),
+    ('\u{a7d0}', '\u{a7d1}'),
+    ('\u{a7d3}', '\u{a7d3}'),
+    ('\u{a7d5}', '\u{a7d9}'),
+    ('\u{a7f2}', '\u{a827}'),
+    ('\u{a82c}', '\u{a82c}'),
+    ('\u{a840}', '\u{a873}'),
+    ('\u{a880}', '\u{a8c5}'),
+    ('\u{a8d0}', '\u{a8d9}'),
+    ('\u{a8e0}', '\u{a8f7}'),
+    ('\u{a8fb}', '\u{a8fb}'),
+    ('\u{a8fd}', '\u{a92d}'),
+    ('\u{a930}', '\u{a953}'),
+    ('\u{a960}', '\u{a97c}'),
+    ('\u{a980}', '\u{a9c0}'),
+    ('\u{a9cf}', '\u{a9d9}'),
+    ('\u{a9e0}', '\u{a9fe}'),
+    ('\u{aa00}', '\u{aa36}'),
+    ('\u{aa40}', '\u{aa4d}'),
+    ('\u{aa50}', '\u{aa59}'),
+    ('\u{aa60}', '\u{aa76}'),
+    ('\u{aa7a}', '\u{aac2}'),
+    ('\u{aadb}', '\u{aadd}'),
+    ('\u{aae0}', '\u{aaef}'),
+    ('\u{aaf2}', '\u{aaf6}'),
+    ('\u{ab01}', '\u{ab06}'),
+    ('\u{ab09}', '\u{ab0e}'),
+    ('\u{ab11}', '\u{ab16}'),
+    ('\u{ab20}', '\u{ab26}'),
+    ('\u{ab28}', '\u{ab2e}'),
+    ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab5c}', '\u{ab69}'),
+    ('\u{ab70}', '\u{abea}'),
+    ('\u{abec}', '\u{abed}'),
+    ('\u{abf0}', '\u{abf9}'),
+    ('\u{ac00}', '\u{d7a3}'),
+    ('\u{d7b0}', '\u{d7c6}'),
+    ('\u{d7cb}', '\u{d7fb}'),
+    ('\u{f900}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'),
+    ('\u{fb00}', '\u{fb06}'),
+    ('\u{fb13}', '\u{fb17}'),
+    ('\u{fb1d}', '\u{fb28}'),
+    ('\u{fb2a}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'),
+    ('\u{fb3e}', '\u{fb3e}'),
+    ('\u{fb40}', '\u{fb41}'),
+    ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fbb1}'),
+    ('\u{fbd3}', '\u{fc5d}'),
+    ('\u{fc64}', '\u{fd3d}'),
+    ('\u{fd50}', '\u{fd8f}'),
+    ('\u{fd92}', '\u{fdc7}'),
+    ('\u{fdf0}', '\u{fdf9}'),
+    ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2f}'),
+    ('\u{fe33}', '\u{fe34}'),
+    ('\u{fe4d}', '\u{fe4f}'),
+    ('\u{fe71}', '\u{fe71}'),
+    ('\u{fe73}', '\u{fe73}'),
+    ('\u{fe77}', '\u{fe77}'),
+    ('\u{fe79}', '\u{fe79}'),
+    ('\u{fe7b}', '\u{fe7b}'),
+    ('\u{fe7d}', '\u{fe7d}'),
+    ('\u{fe7f}', '\u{fefc}'),
+    ('\u{ff10}', '\u{ff19}'),
+    ('\u{ff21}', '\u{ff3a}'),
+    ('\u{ff3f}', '\u{ff3f}'),
+    ('\u{ff41}', '\u{ff5a}'),
+    ('\u{ff66}', '\u{ffbe}'),
+    ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'),
+    ('\u{ffd2}', '\u{ffd7}'),
+    ('\u{ffda}', '\u{ffdc}'),
+    ('\u{10000}', '\u{1000b}'),
+    ('\u{1000d}', '\u{10026}'),
+    ('\u{10028}', '\u{1003a}'),
+    ('\u{1003c}', '\u{1003d}'),
+    ('\u{1003f}', '\u{1004d}'),
+    ('\u{10050}', '\u{1005d}'),
+    ('\u{10080}', '\u{100fa}'),
+    ('\u{10140}', '\u{10174}'),
+    ('\u{101fd}', '\u{101fd}'),
+    ('\u{10280}', '\u{1029c}'),
+    ('\u{102a0}', '\u{102d0}'),
+    ('\u{102e0}', '\u{102e0}'),
+    ('\u{10300}', '\u{1031f}'),
+    ('\u{1032d}', '\u{1034a}'),
+    ('\u{10350}', '\u{1037a}'),
+    ('\u{10380}', '\u{1039d}'),
+    ('\u{103a0}', '\u{103c3}'),
+    ('\u{103c8}', '\u{103cf}'),
+    ('\u{103d1}', '\u{103d5}'),
+    ('\u{10400}', '\u{1049d}'),
+    ('\u{104a0}', '\u{104a9}'),
+    ('\u{104b0}', '\u{104d3}'),
+    ('\u{104d8}', '\u{104fb}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057a}'),
+    ('\u{1057c}', '\u{1058a}'),
+    ('\u{1058c}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105a1}'),
+    ('\u{105a3}', '\u{105b1}'),
+    ('\u{105b3}', '\u{105b9}'),
+    ('\u{105bb}', '\u{105bc}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107b0}'),
+    ('\u{107b2}', '\u{107ba}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080a}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083c}', '\u{1083c}'),
+    ('\u{1083f}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089e}'),
+    ('\u{108e0}', '\u{108f2}'),
+    ('\u{108f4}', '\u{108f5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109b7}'),
+    ('\u{109be}', '\u{109bf}'),
+    ('\u{10a00}', '\u{10a03}'),
+    ('\u{10a05}', '\u{10a06}'),
+    ('\u{10a0c}', '\u{10a13}'),
+    ('\u{10a15}', '\u{10a17}'),
+    ('\u{10a19}', '\u{10a35}'),
+    ('\u{10a38}', '\u{10a3a}'),
+    ('\u{10a3f}', '\u{10a3f}'),
+    ('\u{10a60}', '\u{10a7c}'),
+    ('\u{10a80}', '\u{10a9c}'),
+    ('\u{10ac0}', '\u{10ac7}'),
+    ('\u{10ac9}', '\u{10ae6}'),
+    ('\u{10b00}', '\u{10b35}'),
+    ('\u{10b40}', '\u{10b55}'),
+    ('\u{10b60}', '\u{10b72}'),
+    ('\u{10b80}', '\u{10b91}'),
+    ('\u{10c00}', '\u{10c48}'),
+    ('\u{10c80}', '\u{10cb2}'),
+    ('\u{10cc0}', '\u{10cf2}'),
+    ('\u{10d00}', '\u{10d27}'),
+    ('\u{10d30}', '\u{10d39}'),
+    ('\u{10e80}', '\u{10ea9}'),
+    ('\u{10eab}', '\u{10eac}'),
+    ('\u{10eb0}', '\u{10eb1}'),
+    ('\u{10f00}', '\u{10f1c}'),
+    ('\u{10f27}', '\u{10f27}'),
+    ('\u{10f30}', '\u{10f50}'),
+    ('\u{10f70}', '\u{10f85}'),
+    ('\u{10fb0}', '\u{10fc4}'),
+    ('\u{10fe0}', '\u{10ff6}'),
+    ('\u{11000}', '\u{11046}'),
+    ('\u{11066}', '\u{11075}'),
+    ('\u{1107f}', '\u{110ba}'),
+    ('\u{110c2}', '\u{110c2}'),
+    ('\u{110d0}', '\u{110e8}'),
+    ('\u{110f0}', '\u{110f9}'),
+    ('\u{11100}', '\u{11134}'),
+    ('\u{11136}', '\u{1113f}'),
+    ('\u{11144}', '\u{11147}'),
+    ('\u{11150}', '\u{11173}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11180}', '\u{111c4}'),
+    ('\u{111c9}', '\u{111cc}'),
+    ('\u{111ce}', '\u{111da}'),
+    ('\u{111dc}', '\u{111dc}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{11237}'),
+    ('\u{1123e}', '\u{1123e}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128a}', '\u{1128d}'),
+    ('\u{1128f}', '\u{1129d}'),
+    ('\u{1129f}', '\u{112a8}'),
+    ('\u{112b0}', '\u{112ea}'),
+    ('\u{112f0}', '\u{112f9}'),
+    ('\u{11300}', '\u{11303}'),
+    ('\u{11305}', '\u{1130c}'),
+    ('\u{1130f}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132a}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133b}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'),
+    ('\u{1134b}', '\u{1134d}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{11357}', '\u{11357}'),
+    ('\u{1135d}', '\u{11363}'),
+    ('\u{11366}', '\u{1136c}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{11400}', '\u{1144a}'),
+    ('\u{11450}', '\u{11459}'),
+    ('\u{1145e}', '\u{11461}'),
+    ('\u{11480}', '\u{114c5}'),
+    ('\u{114c7}', '\u{114c7}'),
+    ('\u{114d0}', '\u{114d9}'),
+    ('\u{11580}', '\u{115b5}'),
+    ('\u{115b8}', '\u{115c0}'),
+    ('\u{115d8}', '\u{115dd}'),
+    ('\u{11600}', '\u{11640}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11650}', '\u{11659}'),
+    ('\u{11680}', '\u{116b8}'),
+    ('\u{116c0}', '\u{116c9}'),
+    ('\u{11700}', '\u{1171a}'),
+    ('\u{1171d}', '\u{1172b}'),
+    ('\u{11730}', '\u{11739}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1183a}'),
+    ('\u{118a0}', '\u{118e9}'),
+    ('\u{118ff}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190c}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{11935}'),
+    ('\u{11937}', '\u{11938}'),
+    ('\u{1193b}', '\u{11943}'),
+    ('\u{11950}', '\u{11959}'),
+    ('\u{119a0}', '\u{119a7}'),
+    ('\u{119aa}', '\u{119d7}'),
+    ('\u{119da}', '\u{119e1}'),
+    ('\u{119e3}', '\u{119e4}'),
+    ('\u{11a00}', '\u{11a3e}'),
+    ('\u{11a47}', '\u{11a47}'),
+    ('\u{11a50}', '\u{11a99}'),
+    ('\u{11a9d}', '\u{11a9d}'),
+    ('\u{11ab0}', '\u{11af8}'),
+    ('\u{11c00}', '\u{11c08}'),
+    ('\u{11c0a}', '\u{11c36}'),
+    ('\u{11c38}', '\u{11c40}'),
+    ('\u{11c50}', '\u{11c59}'),
+    ('\u{11c72}', '\u{11c8f}'),
+    ('\u{11c92}', '\u{11ca7}'),
+    ('\u{11ca9}', '\u{11cb6}'),
+    ('\u{11d00}', '\u{11d06}'),
+    ('\u{11d08}', '\u{11d09}'),
+    ('\u{11d0b}', '\u{11d36}'),
+    ('\u{11d3a}', '\u{11d3a}'),
+    ('\u{11d3c}', '\u{11d3d}'),
+    ('\u{11d3f}', '\u{11d47}'),
+    ('\u{11d50}', '\u{11d59}'),
+    ('\u{11d60}', '\u{11d65}'),
+    ('\u{11d67}', '\u{11d68}'),
+    ('\u{11d6a}', '\u{11d8e}'),
+    ('\u{11d90}', '\u{11d91}'),
+    ('\u{11d93}', '\u{11d98}'),
+    ('\u{11da0}', '\u{11da9}'),
+    ('\u{11ee0}', '\u{11ef6}'),
+    ('\u{11fb0}', '\u{11fb0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12400}', '\u{1246e}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12f90}', '\u{12ff0}'),
+    ('\u{13000}', '\u{1342e}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16a38}'),
+    ('\u{16a40}', '\u{16a5e}'),
+    ('\u{16a60}', '\u{16a69}'),
+    ('\u{16a70}', '\u{16abe}'),
+    ('\u{16ac0}', '\u{16ac9}'),
+    ('\u{16ad0}', '\u{16aed}'),
+    ('\u{16af0}', '\u{16af4}'),
+    ('\u{16b00}', '\u{16b36}'),
+    ('\u{16b40}', '\u{16b43}'),
+    ('\u{16b50}', '\u{16b59}'),
+    ('\u{16b63}', '\u{16b77}'),
+    ('\u{16b7d}', '\u{16b8f}'),
+    ('\u{16e40}', '\u{16e7f}'),
+    ('\u{16f00}', '\u{16f4a}'),
+    ('\u{16f4f}', '\u{16f87}'),
+    ('\u{16f8f}', '\u{16f9f}'),
+    ('\u{16fe0}', '\u{16fe1}'),
+    ('\u{16fe3}', '\u{16fe4}'),
+    ('\u{16ff0}', '\u{16ff1}'),
+    ('\u{17000}', '\u{187f7}'),
+    ('\u{18800}', '\u{18cd5}'),
+    ('\u{18d00}', '\u{18d08}'),
+    ('\u{1aff0}', '\u{1aff3}'),
+    ('\u{1aff5}', '\u{1affb}'),
+    ('\u{1affd}', '\u{1affe}'),
+    ('\u{1b000}', '\u{1b122}'),
+    ('\u{1b150}', '\u{1b152}'),
+    ('\u{1b164}', '\u{1b167}'),
+    ('\u{1b170}', '\u{1b2fb}'),
+    ('\u{1bc00}', '\u{1bc6a}'),
+    ('\u{1bc70}', '\u{1bc7c}'),
+    ('\u{1bc80}', '\u{1bc88}'),
+    ('\u{1bc90}', '\u{1bc99}'),
+    ('\u{1bc9d}', '\u{1bc9e}'),
+    ('\u{1cf00}', '\u{1cf2d}'),
+    ('\u{1cf30}', '\u{1cf46}'),
+    ('\u{1d165}', '\u{1d169}'),
+    ('\u{1d16d}', '\u{1d172}'),
+    ('\u{1d17b}', '\u{1d182}'),
+    ('\u{1d185}', '\u{1d18b}'),
+    ('\u{1d1aa}', '\u{1d1ad}'),
+    ('\u{1d242}', '\u{1d244}'),
+    ('\u{1d400}', '\u{1d454}'),
+    ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'),
+    ('\u{1d4a5}', '\u{1d4a6}'),
+    ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'),
+    ('\u{1d4bd}', '\u{1d4c3}'),
+    ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'),
+    ('\u{1d516}', '\u{1d51c}'),
+    ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'),
+    ('\u{1d546}', '\u{1d546}'),
+    ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d6c0}'),
+    ('\u{1d6c2}', '\u{1d6da}'),
+    ('\u{1d6dc}', '\u{1d6fa}'),
+    ('\u{1d6fc}', '\u{1d714}'),
+    ('\u{1d716}', '\u{1d734}'),
+    ('\u{1d736}', '\u{1d74e}'),
+    ('\u{1d750}', '\u{1d76e}'),
+    ('\u{1d770}', '\u{1d788}'),
+    ('\u{1d78a}', '\u{1d7a8}'),
+    ('\u{1d7aa}', '\u{1d7c2}'),
+    ('\u{1d7c4}', '\u{1d7cb}'),
+    ('\u{1d7ce}', '\u{1d7ff}'),
+    ('\u{1da00}', '\u{1da36}'),
+    ('\u{1da3b}', '\u{1da6c}'),
+    ('\u{1da75}', '\u{1da75}'),
+    ('\u{1da84}', '\u{1da84}'),
+    ('\u{1da9b}', '\u{1da9f}'),
+    ('\u{1daa1}', '\u{1daaf}'),
+    ('\u{1df00}', '\u{1df1e}'),
+    ('\u{1e000}', '\u{1e006}'),
+    ('\u{1e008}', '\u{1e018}'),
+    ('\u{1e01b}', '\u{1e021}'),
+    ('\u{1e023}', '\u{1e024}'),
+    ('\u{1e026}', '\u{1e02a}'),
+    ('\u{1e100}', '\u{1e12c}'),
+    ('\u{1e130}', '\u{1e13d}'),
+    ('\u{1e140}', '\u{1e149}'),
+    ('\u{1e14e}', '\u{1e14e}'),
+    ('\u{1e290}', '\u{1e2ae}'),
+    ('\u{1e2c0}', '\u{1e2f9}'),
+    ('\u{1e7e0}', '\u{1e7e6}'),
+    ('\u{1e7e8}', '\u{1e7eb}'),
+    ('\u{1e7ed}', '\u{1e7ee}'),
+    ('\u{1e7f0}', '\u{1e7fe}'),
+    ('\u{1e800}', '\u{1e8c4}'),
+    ('\u{1e8d0}', '\u{1e8d6}'),
+    ('\u{1e900}', '\u{1e94b}'),
+    ('\u{1e950}', '\u{1e959}'),
+    ('\u{1ee00}', '\u{1ee03}'),
+    ('\u{1ee05}', '\u{1ee1f}'),
+    ('\u{1ee21}', '\u{1ee22}'),
+    ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'),
+    ('\u{1ee29}', '\u{1ee32}'),
+    ('\u{1ee34}', '\u{1ee37}'),
+    ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'),
+    ('\u{1ee42}', '\u{1ee42}'),
+    ('\u{1ee47}', '\u{1ee47}'),
+    ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'),
+    ('\u{1ee4d}', '\u{1ee4f}'),
+    ('\u{1ee51}', '\u{1ee52}'),
+    ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'),
+    ('\u{1ee59}', '\u{1ee59}'),
+    ('\u{1ee5b}', '\u{1ee5b}'),
+    ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'),
+    ('\u{1ee61}', '\u{1ee62}'),
+    ('\u{1ee64}', '\u{1ee64}'),
+    ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'),
+    ('\u{1ee74}', '\u{1ee77}'),
+    ('\u{1ee79}', '\u{1ee7c}'),
+    ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'),
+    ('\u{1ee8b}', '\u{1ee9b}'),
+    ('\u{1eea1}', '\u{1eea3}'),
+    ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'),
+    ('\u{1fbf0}', '\u{1fbf9}'),
+    ('\u{20000}', '\u{2a6df}'),
+    ('\u{2a700}', '\u{2b738}'),
+    ('\u{2b740}', '\u{2b81d}'),
+    ('\u{2b820}', '\u{2cea1}'),
+    ('\u{2ceb0}', '\u{2ebe0}'),
+    ('\u{2f800}', '\u{2fa1d}'),
+    ('\u{30000}', '\u{3134a}'),
+    ('\u{e0100}', '\u{e01ef}'),
+];